@@ -0,0 +1,167 @@
+/// Known answers for each day's puzzle input, to verify solutions against.
+use crate::{InputError, ParseError};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The known answers for a day, where either part may not be known.
+///
+/// Stored in a file like:
+///
+/// ```text
+/// part one: 1292
+/// part two:
+/// #..#.####
+/// #..#.#...
+/// ```
+///
+/// where each answer either follows its label on the same line, or takes up
+/// every line until the next label when it spans multiple lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers: [Option<String>; 2] = [None, None];
+        // The answer currently spanning multiple lines, if any.
+        let mut current = None;
+
+        let mut offset = 0;
+        for line in s.split_inclusive('\n') {
+            let line_offset = offset;
+            offset += line.len();
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            match line.split_once(':') {
+                Some((label @ ("part one" | "part two"), rest)) => {
+                    let part = if label == "part one" { 0 } else { 1 };
+                    if answers[part].is_some() {
+                        return Err(ParseError::new(label, "Answer given more than once")
+                            .offset_by(s, line_offset));
+                    }
+                    answers[part] = Some(rest.trim().to_string());
+                    current = rest.trim().is_empty().then_some(part);
+                }
+                _ => match current {
+                    Some(part) => {
+                        let answer = answers[part].get_or_insert_with(String::new);
+                        if !answer.is_empty() {
+                            answer.push('\n');
+                        }
+                        answer.push_str(line);
+                    }
+                    None if line.trim().is_empty() => (),
+                    None => {
+                        return Err(ParseError::new(line, "Expected 'part one:' or 'part two:'")
+                            .offset_by(s, line_offset))
+                    }
+                },
+            }
+        }
+
+        // Trailing blank lines aren't part of a multi-line answer, and a label
+        // with no answer means it isn't known.
+        let [part_one, part_two] = answers.map(|answer| {
+            answer
+                .map(|a| a.trim_end().to_string())
+                .filter(|a| !a.is_empty())
+        });
+        Ok(Self { part_one, part_two })
+    }
+}
+
+/// Default directory to read answers from: the "answers" folder in the
+/// crate's manifest directory.
+pub fn default_answers_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers")
+}
+
+/// Read the known answers for a day from e.g. "05.txt" in a directory.
+///
+/// A missing file means neither answer is known.
+pub fn read_answers(dir: &Path, day: u8) -> Result<Answers, String> {
+    let path = dir.join(format!("{:02}.txt", day));
+    match fs::read_to_string(&path) {
+        Ok(contents) => contents
+            .parse()
+            .map_err(|e| format!("Invalid answers file {}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(source) => Err(InputError::File { path, source }.to_string()),
+    }
+}
+
+/// The result of checking an answer against the known answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+impl Outcome {
+    /// Check an answer against the known answer, if there is one.
+    pub fn check(expected: Option<&str>, actual: &str) -> Self {
+        match expected {
+            None => Self::Missing,
+            Some(expected) if expected.trim_end() == actual.trim_end() => Self::Pass,
+            Some(expected) => Self::Fail {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Missing => write!(f, "missing"),
+            Self::Fail { expected, actual } if expected.contains('\n') || actual.contains('\n') => {
+                write!(f, "FAIL\nexpected:\n{}\nactual:\n{}", expected, actual)
+            }
+            Self::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, actual {})", expected, actual)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = "part one: 17\npart two:\n#####\n#...#\n\n".parse().unwrap();
+        assert_eq!(answers.part_one.as_deref(), Some("17"));
+        assert_eq!(answers.part_two.as_deref(), Some("#####\n#...#"));
+
+        let answers: Answers = "part two: 36\n".parse().unwrap();
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.part_two.as_deref(), Some("36"));
+
+        let e = "part one: 1\npart three: 3".parse::<Answers>().unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert!("part one: 1\npart one: 2".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Outcome::check(Some("10"), "10"), Outcome::Pass);
+        assert_eq!(Outcome::check(None, "10"), Outcome::Missing);
+        assert_eq!(
+            Outcome::check(Some("10"), "11"),
+            Outcome::Fail {
+                expected: "10".to_string(),
+                actual: "11".to_string()
+            }
+        );
+    }
+}
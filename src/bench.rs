@@ -0,0 +1,271 @@
+/// Benchmarking of each stage of a day's solution over many iterations.
+use crate::{ParseError, Solution};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How many times to run each stage of a solution when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    /// Untimed runs of every stage before timing starts.
+    pub warmup: usize,
+    /// Timed runs of every stage.
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Statistics over the time taken by every timed run of a stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculate the statistics of some (non-empty) samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need samples to calculate statistics");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let len = sorted.len();
+
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / len as f64;
+        // Use the sample standard deviation, as the runs are a sample of every
+        // possible run.
+        let variance = if len > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (len - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings of each stage of a day's solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl BenchReport {
+    /// Each stage's name paired with its timings.
+    pub fn stages(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part_one", &self.part_one),
+            ("part_two", &self.part_two),
+        ]
+    }
+}
+
+/// Time a closure, keeping its result from being optimised away.
+fn time<T>(function: impl Fn() -> T) -> Duration {
+    let now = Instant::now();
+    black_box(function());
+    now.elapsed()
+}
+
+/// Benchmark parsing the input and solving each part of a solution.
+pub fn bench<S: Solution>(input: &str, config: &BenchConfig) -> Result<BenchReport, ParseError> {
+    let parsed = S::parse(input)?;
+
+    for _ in 0..config.warmup {
+        black_box(S::parse(black_box(input))?);
+        black_box(S::part_one(black_box(&parsed)));
+        black_box(S::part_two(black_box(&parsed)));
+    }
+
+    let iterations = config.iterations.max(1);
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part_one = Vec::with_capacity(iterations);
+    let mut part_two = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        parse.push(time(|| S::parse(black_box(input))));
+        part_one.push(time(|| S::part_one(black_box(&parsed))));
+        part_two.push(time(|| S::part_two(black_box(&parsed))));
+    }
+
+    Ok(BenchReport {
+        day: S::DAY,
+        iterations,
+        parse: Stats::from_samples(&parse),
+        part_one: Stats::from_samples(&part_one),
+        part_two: Stats::from_samples(&part_two),
+    })
+}
+
+/// Format to output benchmark reports in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(ParseError::new(s, "Format should be text, json or csv")),
+        }
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e6
+}
+
+/// Format benchmark reports for output, with all times in microseconds.
+pub fn format_reports(reports: &[BenchReport], format: Format) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Text => {
+            for report in reports {
+                writeln!(
+                    out,
+                    "Day {:02} ({} iterations):",
+                    report.day, report.iterations
+                )
+                .unwrap();
+                for (stage, stats) in report.stages() {
+                    writeln!(
+                        out,
+                        "  {:<8}  min {:>10.1}µs  median {:>10.1}µs  mean {:>10.1}µs  stddev {:>10.1}µs",
+                        stage,
+                        micros(stats.min),
+                        micros(stats.median),
+                        micros(stats.mean),
+                        micros(stats.stddev)
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        Format::Json => {
+            let days: Vec<String> = reports
+                .iter()
+                .map(|report| {
+                    let stages: Vec<String> = report
+                        .stages()
+                        .iter()
+                        .map(|(stage, stats)| {
+                            format!(
+                                "\"{}\":{{\"min_us\":{:.3},\"median_us\":{:.3},\"mean_us\":{:.3},\"stddev_us\":{:.3}}}",
+                                stage,
+                                micros(stats.min),
+                                micros(stats.median),
+                                micros(stats.mean),
+                                micros(stats.stddev)
+                            )
+                        })
+                        .collect();
+                    format!(
+                        "{{\"day\":{},\"iterations\":{},{}}}",
+                        report.day,
+                        report.iterations,
+                        stages.join(",")
+                    )
+                })
+                .collect();
+            writeln!(out, "[{}]", days.join(",")).unwrap();
+        }
+        Format::Csv => {
+            writeln!(
+                out,
+                "day,stage,iterations,min_us,median_us,mean_us,stddev_us"
+            )
+            .unwrap();
+            for report in reports {
+                for (stage, stats) in report.stages() {
+                    writeln!(
+                        out,
+                        "{},{},{},{:.3},{:.3},{:.3},{:.3}",
+                        report.day,
+                        stage,
+                        report.iterations,
+                        micros(stats.min),
+                        micros(stats.median),
+                        micros(stats.mean),
+                        micros(stats.stddev)
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(n: u64) -> Duration {
+        Duration::from_micros(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[us(4), us(2), us(9), us(5)]);
+        assert_eq!(stats.min, us(2));
+        assert_eq!(stats.median, us(4) + us(1) / 2);
+        assert_eq!(stats.mean, us(5));
+        assert_eq!(stats.stddev.as_micros(), 2);
+
+        let stats = Stats::from_samples(&[us(7)]);
+        assert_eq!((stats.min, stats.median, stats.mean), (us(7), us(7), us(7)));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_format_reports() {
+        let stats = Stats::from_samples(&[us(1), us(3)]);
+        let reports = vec![BenchReport {
+            day: 1,
+            iterations: 2,
+            parse: stats,
+            part_one: stats,
+            part_two: stats,
+        }];
+
+        let csv = format_reports(&reports, Format::Csv);
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "1,parse,2,1.000,2.000,2.000,1.414");
+
+        let json = format_reports(&reports, Format::Json);
+        assert!(json.starts_with("[{\"day\":1,\"iterations\":2,\"parse\":{\"min_us\":1.000,"));
+    }
+}
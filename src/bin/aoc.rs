@@ -0,0 +1,201 @@
+/// Unified runner for the solution to every day's challenge.
+use aoc2021::answers::{default_answers_dir, read_answers, Outcome};
+use aoc2021::bench::{format_reports, BenchConfig, Format};
+use aoc2021::days::{get_day, parse_days};
+use aoc2021::solution::Runner;
+use aoc2021::{InputSource, ParseError};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage:
+    aoc run <DAYS> [--input <PATH>]
+    aoc bench <DAYS> [--input <PATH>] [--warmup <N>] [--iterations <N>] [--format <FORMAT>]
+    aoc verify [<DAYS>] [--input <PATH>] [--answers <DIR>]
+    aoc inspect <DAY> [--input <PATH>] [<ARGS>...]
+
+DAYS is a single day (e.g. 5), a range of days (e.g. 1..=17 or 1..5) or \"all\".
+
+PATH is a file to read a single day's input from, a directory containing a file
+for each day (e.g. 05.txt), or \"-\" to read from stdin. By default this is the
+directory in the AOC_INPUT_DIR environment variable, or else the crate's
+\"input\" folder.
+
+Benchmarks warm up for 3 untimed runs then time 20 runs of each stage by
+default, reporting the results as text, json or csv.
+
+Verifying checks every day (unless DAYS are given) against the known answers
+in DIR, by default the crate's \"answers\" folder, where e.g. 05.txt holds:
+
+    part one: <ANSWER>
+    part two: <ANSWER>
+
+Inspecting runs a mode specific to a single day, such as drawing the input,
+passing it any other arguments:
+
+    aoc inspect 12 [--path <CAVE,CAVE,...>]
+    aoc inspect 13 [--folds <N>]
+    aoc inspect 14 [--reachable]
+    aoc inspect 15 [--tiles <N>] [--format ansi|ppm|svg]
+    aoc inspect 16 [--format sexpr|infix|trace]
+    aoc inspect 17 [--velocity <DX,DY>]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    Run,
+    Bench,
+    Verify,
+    Inspect,
+}
+
+/// Options given to a command.
+struct Options {
+    days: Vec<u8>,
+    input: InputSource,
+    bench: BenchConfig,
+    format: Format,
+    answers: PathBuf,
+    /// Arguments left over for the day to inspect the input with.
+    extra: Vec<String>,
+}
+
+fn parse_options(command: Command, args: &[&str]) -> Result<Options, String> {
+    let mut days = None;
+    let mut input = None;
+    let mut bench = BenchConfig::default();
+    let mut format = Format::Text;
+    let mut answers = default_answers_dir();
+    let mut extra = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .copied()
+                .ok_or(format!("Missing value for {}", arg))
+        };
+        let parse_count = |s: &str| {
+            s.parse::<usize>()
+                .map_err(|_| format!("Invalid count for {}: {}", arg, s))
+        };
+        match (command, *arg) {
+            (_, "-i" | "--input") => input = Some(value()?),
+            (Command::Bench, "--warmup") => bench.warmup = parse_count(value()?)?,
+            (Command::Bench, "--iterations") => bench.iterations = parse_count(value()?)?,
+            (Command::Bench, "--format") => {
+                format = value()?.parse().map_err(|e: ParseError| e.reason)?
+            }
+            (Command::Verify, "--answers") => answers = PathBuf::from(value()?),
+            (_, spec) if days.is_none() && !spec.starts_with('-') => days = Some(parse_days(spec)?),
+            (Command::Inspect, other) => extra.push(other.to_string()),
+            (_, other) => return Err(format!("Unexpected argument: {}\n\n{}", other, USAGE)),
+        }
+    }
+
+    let days = match (command, days) {
+        (_, Some(days)) => days,
+        (Command::Verify, None) => parse_days("all")?,
+        _ => return Err(USAGE.to_string()),
+    };
+    let input = InputSource::resolve(input);
+    if input.is_single() && days.len() > 1 {
+        return Err("A single input can only be used when running a single day".to_string());
+    }
+    if command == Command::Inspect && days.len() > 1 {
+        return Err("Only a single day can be inspected at a time".to_string());
+    }
+
+    Ok(Options {
+        days,
+        input,
+        bench,
+        format,
+        answers,
+        extra,
+    })
+}
+
+/// Get the solution and input for every day selected.
+fn solutions(options: &Options) -> Result<Vec<(&'static dyn Runner, String)>, String> {
+    options
+        .days
+        .iter()
+        .map(|day| {
+            let solution = get_day(*day).ok_or(format!("No solution for day {}", day))?;
+            let input = options.input.read(*day).map_err(|e| e.to_string())?;
+            Ok((solution, input))
+        })
+        .collect()
+}
+
+fn run(options: Options) -> Result<(), String> {
+    for (i, (solution, input)) in solutions(&options)?.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        solution.run(&input)?;
+    }
+    Ok(())
+}
+
+fn bench(options: Options) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for (solution, input) in solutions(&options)? {
+        eprintln!("Benchmarking day {:02}...", solution.day());
+        reports.push(solution.bench(&input, &options.bench)?);
+    }
+    print!("{}", format_reports(&reports, options.format));
+    Ok(())
+}
+
+fn verify(options: Options) -> Result<(), String> {
+    let mut failed = false;
+    for (solution, input) in solutions(&options)? {
+        let known = read_answers(&options.answers, solution.day())?;
+        let outcomes = match solution.solve(&input) {
+            Ok((part_one, part_two)) => [
+                Outcome::check(known.part_one.as_deref(), &part_one),
+                Outcome::check(known.part_two.as_deref(), &part_two),
+            ],
+            Err(e) => {
+                println!("Day {:02}: FAIL ({})", solution.day(), e);
+                failed = true;
+                continue;
+            }
+        };
+
+        for (part, outcome) in ["one", "two"].iter().zip(outcomes) {
+            failed |= matches!(outcome, Outcome::Fail { .. });
+            println!("Day {:02} part {}: {}", solution.day(), part, outcome);
+        }
+    }
+
+    if failed {
+        Err("Some answers didn't match the known answers".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn inspect(options: Options) -> Result<(), String> {
+    for (solution, input) in solutions(&options)? {
+        print!("{}", solution.inspect(&input, &options.extra)?);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match &args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", rest @ ..] => parse_options(Command::Run, rest).and_then(run),
+        ["bench", rest @ ..] => parse_options(Command::Bench, rest).and_then(bench),
+        ["verify", rest @ ..] => parse_options(Command::Verify, rest).and_then(verify),
+        ["inspect", rest @ ..] => parse_options(Command::Inspect, rest).and_then(inspect),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -1,8 +1,8 @@
 /// Solution to Advent of Code Challenge Day 01.
-use aoc2021::{get_day_input, parse_input_lines, print_elapsed_time};
+use crate::{try_parse_input_lines, ParseError, Solution};
 
 /// Find the number of times the depth increases between measurements.
-fn part_one(input: &Vec<u32>) -> u32 {
+fn part_one(input: &[u32]) -> u32 {
     let mut inc_count = 0;
     for (num1, num2) in input.iter().zip(&input[1..]) {
         if num2 > num1 {
@@ -13,7 +13,7 @@ fn part_one(input: &Vec<u32>) -> u32 {
 }
 
 /// Find the product of the three numbers which sum to the target value.
-fn part_two(input: &Vec<u32>) -> u32 {
+fn part_two(input: &[u32]) -> u32 {
     let mut window_sums = Vec::new();
     for window in input.windows(3) {
         window_sums.push(window.iter().sum());
@@ -21,13 +21,26 @@ fn part_two(input: &Vec<u32>) -> u32 {
     part_one(&window_sums)
 }
 
-fn main() {
-    let input = get_day_input("01");
-    let num_list = parse_input_lines(&input);
-    println!("Day 01:");
-    println!("==========");
-    println!("Part one: {}", print_elapsed_time(|| part_one(&num_list)));
-    println!("Part two: {}", print_elapsed_time(|| part_two(&num_list)));
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_lines(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -48,7 +61,7 @@ mod tests {
 263"
         .to_string();
 
-        let num_list = parse_input_lines(&input);
+        let num_list = Day01::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&num_list), 7);
         assert_eq!(part_two(&num_list), 5);
@@ -1,40 +1,36 @@
 /// Solution to Advent of Code Challenge Day 02.
-use aoc2021::{get_day_input, parse_input_lines, print_elapsed_time};
-use std::io;
+use crate::{try_parse_input_lines, ParseError, Solution};
 use std::str::FromStr;
 
-#[derive(PartialEq)]
-enum Instruction {
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
     Forward(u32),
     Down(u32),
     Up(u32),
 }
 
 impl FromStr for Instruction {
-    type Err = io::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let vec: Vec<_> = s.split_whitespace().collect();
-        let (instr, val) = match &vec[..] {
-            &[first, second, ..] => (
-                first,
-                second
-                    .parse::<u32>()
-                    .expect("Instruction value is not a valid unsigned integer"),
-            ),
-            _ => unreachable!(),
-        };
+        let (instr, val) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s, "Instruction should be a name and a value"))?;
+        let val = val.parse::<u32>().map_err(|e| {
+            ParseError::new(val, format!("Instruction value is not valid: {}", e))
+                .offset_by(s, instr.len() + 1)
+        })?;
         Ok(match instr {
             "forward" => Self::Forward(val),
             "down" => Self::Down(val),
             "up" => Self::Up(val),
-            _ => panic!("Instruction name is not supported"),
+            _ => return Err(ParseError::new(instr, "Instruction name is not supported")),
         })
     }
 }
 
 /// Find the horizontal and depth positions multiplied together
 /// Uses Part One instruction meanings
-fn part_one(input: &Vec<Instruction>) -> u32 {
+fn part_one(input: &[Instruction]) -> u32 {
     let mut h = 0;
     let mut d = 0;
 
@@ -51,7 +47,7 @@ fn part_one(input: &Vec<Instruction>) -> u32 {
 
 /// Find the horizontal and depth positions multiplied together
 /// Uses Part Two instruction meanings
-fn part_two(input: &Vec<Instruction>) -> u32 {
+fn part_two(input: &[Instruction]) -> u32 {
     let mut a = 0;
     let mut h = 0;
     let mut d = 0;
@@ -70,19 +66,26 @@ fn part_two(input: &Vec<Instruction>) -> u32 {
     h * d
 }
 
-fn main() {
-    let input = get_day_input("02");
-    let instructions = parse_input_lines(&input);
-    println!("Day 02:");
-    println!("==========");
-    println!(
-        "Part one: {}",
-        print_elapsed_time(|| part_one(&instructions))
-    );
-    println!(
-        "Part two: {}",
-        print_elapsed_time(|| part_two(&instructions))
-    );
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Instruction>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_lines(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -99,9 +102,18 @@ down 8
 forward 2"
             .to_string();
 
-        let instructions = parse_input_lines(&input);
+        let instructions = Day02::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&instructions), 150);
         assert_eq!(part_two(&instructions), 900);
     }
+
+    #[test]
+    fn test_invalid_input() {
+        let e = Day02::parse("forward 5\ndown x").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 6, "x"));
+
+        let e = Day02::parse("forward 5\nsideways 5").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "sideways"));
+    }
 }
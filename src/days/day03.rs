@@ -0,0 +1,187 @@
+/// Solution to Advent of Code Challenge Day 03.
+use crate::{try_parse_input_lines, ParseError, Solution};
+
+fn get_bit_sums(input: &[String]) -> Vec<(u32, u32)> {
+    let bit_num = input[0].len();
+
+    let mut bit_sums: Vec<(u32, u32)> = Vec::with_capacity(bit_num);
+
+    for _ in 0..bit_num {
+        bit_sums.push((0, 0));
+    }
+
+    for bin_str in input.iter() {
+        for (i, ch) in bin_str.chars().enumerate() {
+            match ch {
+                '0' => bit_sums[i].0 += 1,
+                '1' => bit_sums[i].1 += 1,
+                _ => unreachable!("Rows are checked to be binary when parsed"),
+            }
+        }
+    }
+
+    bit_sums
+}
+
+fn get_bit_sum(input: &[String], char_index: usize) -> (u32, u32) {
+    let mut bit_sum = (0, 0);
+    for bin_str in input.iter() {
+        if let Some(ch) = bin_str.chars().nth(char_index) {
+            match ch {
+                '0' => bit_sum.0 += 1,
+                '1' => bit_sum.1 += 1,
+                _ => unreachable!("Rows are checked to be binary when parsed"),
+            }
+        }
+    }
+
+    bit_sum
+}
+
+fn get_filtered_num(mut input: Vec<String>, most_common: bool) -> String {
+    let one_majority_ch = if most_common { '1' } else { '0' };
+    let one_minority_ch = if most_common { '0' } else { '1' };
+    for bit_idx in 0..input[0].len() {
+        if input.len() == 1 {
+            break;
+        }
+
+        let bit_sum = get_bit_sum(&input, bit_idx);
+        let zeroes = bit_sum.0;
+        let ones = bit_sum.1;
+
+        if ones >= zeroes {
+            // Only keep values with the char in position bit_idx needed whena
+            // ones are the majority.
+            input.retain(|num| num.chars().nth(bit_idx) == Some(one_majority_ch));
+        } else {
+            // Only keep values with the char in position bit_idx needed whena
+            // ones are the minority.
+            input.retain(|num| num.chars().nth(bit_idx) == Some(one_minority_ch));
+        }
+    }
+    input[0].clone()
+}
+
+/// Get the rows of the diagnostic report, checking they're all binary numbers
+/// of the same width.
+fn get_report(input: &str) -> Result<Vec<String>, ParseError> {
+    let rows: Vec<String> = try_parse_input_lines(input)?;
+    let width = rows
+        .first()
+        .map(String::len)
+        .ok_or_else(|| ParseError::new(input, "Expected at least one binary number"))?;
+
+    let mut offset = 0;
+    for (line, row) in input.split_inclusive('\n').zip(&rows) {
+        let error = |text: &str, at: usize, reason: &str| {
+            Err(ParseError::new(text, reason).offset_by(input, offset + at))
+        };
+        if let Some((i, ch)) = row.char_indices().find(|(_, ch)| !matches!(ch, '0' | '1')) {
+            return error(&row[i..i + ch.len_utf8()], i, "Expected a binary digit");
+        }
+        if row.is_empty() {
+            return error(row, 0, "Expected a binary number");
+        }
+        if row.len() != width {
+            let reason = format!("Number has {} bits but the first has {}", row.len(), width);
+            return error(row, 0, &reason);
+        }
+        offset += line.len();
+    }
+    Ok(rows)
+}
+
+/// Find the most common and least common bit combinations and multiply
+fn part_one(input: &[String]) -> u64 {
+    let mut gamma_str = String::new();
+    let mut epsilon_str = String::new();
+
+    for (zeroes, ones) in get_bit_sums(input) {
+        if ones > zeroes {
+            gamma_str.push('1');
+            epsilon_str.push('0');
+        } else {
+            gamma_str.push('0');
+            epsilon_str.push('1');
+        }
+    }
+
+    let gamma = u64::from_str_radix(&gamma_str, 2).expect("Resulting gamma string wasn't binary");
+    let epsilon =
+        u64::from_str_radix(&epsilon_str, 2).expect("Resulting epsilon string wasn't binary");
+
+    gamma * epsilon
+}
+
+/// Find the filtered numbers when considering bit majorities in each position
+fn part_two(input: &[String]) -> u64 {
+    let og_string = get_filtered_num(input.to_vec(), true);
+    let cs_string = get_filtered_num(input.to_vec(), false);
+
+    let og = u64::from_str_radix(&og_string, 2).expect("Resulting OG string wasn't binary");
+    let cs = u64::from_str_radix(&cs_string, 2).expect("Resulting CS string wasn't binary");
+
+    og * cs
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_report(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010"
+            .to_string();
+
+        let inputs = Day03::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs), 198);
+        assert_eq!(part_two(&inputs), 230);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let located = |input: &str| {
+            let e = Day03::parse(input).unwrap_err();
+            (e.line, e.column, e.text)
+        };
+        assert_eq!(located("0101\n01201\n"), (2, 3, "2".to_string()));
+        assert_eq!(located("0101\n011\n"), (2, 1, "011".to_string()));
+        assert_eq!(located("0101\n\n0110"), (2, 1, String::new()));
+        assert!(Day03::parse("").is_err());
+    }
+}
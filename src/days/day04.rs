@@ -0,0 +1,494 @@
+/// Solution to Advent of Code Challenge Day 04.
+use crate::grid::{Coord, Grid};
+use crate::{offset_within, try_parse_input_lines, try_parse_input_with, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Line(Vec<u32>);
+
+impl FromStr for Line {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(try_parse_input_with(s, |s| {
+            s.split(' ').filter(|s| !s.is_empty())
+        })?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BingoGrid {
+    numbers: Grid<u32>,
+}
+
+impl FromStr for BingoGrid {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Line> = try_parse_input_lines(s)?;
+        let width = rows.first().map_or(0, |row| row.0.len());
+        if width == 0 {
+            return Err(ParseError::new(s, "Board should have at least one number"));
+        }
+
+        let mut seen = HashSet::new();
+        let mut offset = 0;
+        for (line, row) in s.split_inclusive('\n').zip(&rows) {
+            if row.0.len() != width {
+                let reason = format!(
+                    "Row has {} numbers but the first row has {}",
+                    row.0.len(),
+                    width
+                );
+                return Err(ParseError::new(line.trim_end(), reason).offset_by(s, offset));
+            }
+
+            let texts = line.split_whitespace();
+            for (text, number) in texts.zip(&row.0) {
+                if !seen.insert(*number) {
+                    let e = ParseError::new(text, "Number is on the board more than once");
+                    return Err(e.offset_by(s, offset_within(s, text)));
+                }
+            }
+            offset += line.len();
+        }
+
+        let height = rows.len();
+        let cells = rows.into_iter().flat_map(|row| row.0).collect();
+        Ok(Self {
+            numbers: Grid::new(width, height, cells),
+        })
+    }
+}
+
+impl BingoGrid {
+    /// Get the numbers of the board, by their position on it.
+    pub fn numbers(&self) -> &Grid<u32> {
+        &self.numbers
+    }
+}
+
+/// A board winning, which happens at most once for each board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Win {
+    /// Index of the board in the order boards were given.
+    pub board: usize,
+    /// Index of the draw which won, in the order numbers are drawn.
+    pub turn: usize,
+    /// The number drawn which won.
+    pub number: u32,
+    /// The sum of the board's unmarked numbers times the number drawn.
+    pub score: u32,
+}
+
+/// What happens when more than one board wins on the same number drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Ties {
+    /// Every board which wins on the number wins, in the order of the boards.
+    #[default]
+    Share,
+    /// The game stops with an error, so that which board wins first or last
+    /// is never down to the order of the boards.
+    Reject,
+}
+
+/// Which marked cells win a board, and what happens when several boards win
+/// on the same number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rules {
+    /// Whether marking a whole row wins.
+    pub rows: bool,
+    /// Whether marking a whole column wins.
+    pub columns: bool,
+    /// Whether marking either whole diagonal wins, which only square boards
+    /// have.
+    pub diagonals: bool,
+    /// Whether boards only win when every cell is marked, ignoring the other
+    /// rules.
+    pub full_house: bool,
+    /// What happens when more than one board wins on the same number.
+    pub ties: Ties,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            rows: true,
+            columns: true,
+            diagonals: false,
+            full_house: false,
+            ties: Ties::Share,
+        }
+    }
+}
+
+impl Rules {
+    /// Create the rules of the puzzle, where rows and columns win.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether marking a whole row wins.
+    pub fn rows(mut self, rows: bool) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set whether marking a whole column wins.
+    pub fn columns(mut self, columns: bool) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set whether marking either whole diagonal wins.
+    pub fn diagonals(mut self, diagonals: bool) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
+    /// Set whether boards only win when every cell is marked.
+    pub fn full_house(mut self, full_house: bool) -> Self {
+        self.full_house = full_house;
+        self
+    }
+
+    /// Set what happens when more than one board wins on the same number.
+    pub fn ties(mut self, ties: Ties) -> Self {
+        self.ties = ties;
+        self
+    }
+
+    /// Check every board can be played by the rules.
+    pub fn check(&self, boards: &[BingoGrid]) -> Result<(), RulesError> {
+        if !(self.rows || self.columns || self.diagonals || self.full_house) {
+            return Err(RulesError::NoWayToWin);
+        }
+        if self.diagonals && !self.full_house {
+            let not_square = boards
+                .iter()
+                .position(|b| b.numbers.width() != b.numbers.height());
+            if let Some(board) = not_square {
+                let numbers = &boards[board].numbers;
+                return Err(RulesError::NotSquare {
+                    board,
+                    width: numbers.width(),
+                    height: numbers.height(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error from rules which can't be played with some boards or draws.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RulesError {
+    /// Nothing a board can mark wins.
+    NoWayToWin,
+    /// Diagonals win but a board isn't square, so has no diagonals.
+    NotSquare {
+        board: usize,
+        width: usize,
+        height: usize,
+    },
+    /// More than one board won on the same number when ties are rejected.
+    Tie {
+        /// Index of the draw which they won on.
+        turn: usize,
+        /// Indices of the boards which won, in order.
+        boards: Vec<usize>,
+    },
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoWayToWin => write!(f, "Rules don't allow any board to win"),
+            Self::NotSquare {
+                board,
+                width,
+                height,
+            } => write!(
+                f,
+                "Board {} is {}x{}, so has no diagonals to win with",
+                board, width, height
+            ),
+            Self::Tie { turn, boards } => {
+                write!(f, "Boards {:?} all win on draw {}", boards, turn)
+            }
+        }
+    }
+}
+
+impl Error for RulesError {}
+
+/// Progress of a board through a game.
+#[derive(Debug, Clone)]
+struct Card {
+    marked: Grid<bool>,
+    /// Number of marked cells in each row.
+    row_hits: Vec<usize>,
+    /// Number of marked cells in each column.
+    column_hits: Vec<usize>,
+    /// Number of marked cells on the diagonal from the top left, then from
+    /// the top right.
+    diagonal_hits: [usize; 2],
+    marked_count: usize,
+    unmarked_sum: u32,
+    won: bool,
+}
+
+impl Card {
+    fn new(board: &BingoGrid) -> Self {
+        let numbers = &board.numbers;
+        Self {
+            marked: Grid::from_fn(numbers.width(), numbers.height(), |_| false),
+            row_hits: vec![0; numbers.height()],
+            column_hits: vec![0; numbers.width()],
+            diagonal_hits: [0; 2],
+            marked_count: 0,
+            unmarked_sum: numbers.iter().map(|(_, number)| number).sum(),
+            won: false,
+        }
+    }
+
+    /// Mark the cell of a number drawn, giving whether the board has won by
+    /// the rules.
+    fn mark(&mut self, board: &BingoGrid, (x, y): Coord, rules: &Rules) -> bool {
+        if self.marked[(x, y)] {
+            return false;
+        }
+        let (width, height) = (board.numbers.width(), board.numbers.height());
+        self.marked[(x, y)] = true;
+        self.marked_count += 1;
+        self.unmarked_sum -= board.numbers[(x, y)];
+        self.row_hits[y] += 1;
+        self.column_hits[x] += 1;
+        let on_diagonals = [x == y, x + y + 1 == width];
+        for (hits, on_diagonal) in self.diagonal_hits.iter_mut().zip(on_diagonals) {
+            *hits += on_diagonal as usize;
+        }
+
+        if rules.full_house {
+            return self.marked_count == board.numbers.len();
+        }
+        (rules.rows && self.row_hits[y] == width)
+            || (rules.columns && self.column_hits[x] == height)
+            || (rules.diagonals
+                && on_diagonals
+                    .iter()
+                    .zip(self.diagonal_hits)
+                    .any(|(on_diagonal, hits)| *on_diagonal && hits == width))
+    }
+}
+
+/// Play bingo on every board until the numbers drawn run out, giving each
+/// board's win in the order they happen, or by the order of the boards when
+/// they win on the same draw unless the rules reject ties.
+///
+/// Boards which never win are left out.
+pub fn play(boards: &[BingoGrid], draw: &[u32], rules: &Rules) -> Result<Vec<Win>, RulesError> {
+    rules.check(boards)?;
+
+    // Find the cells of each number on every board up front, rather than
+    // searching every board on each draw.
+    let mut cells: HashMap<u32, Vec<(usize, Coord)>> = HashMap::new();
+    for (i, board) in boards.iter().enumerate() {
+        for (coord, number) in board.numbers.iter() {
+            cells.entry(*number).or_default().push((i, coord));
+        }
+    }
+
+    let mut cards: Vec<Card> = boards.iter().map(Card::new).collect();
+    let mut wins = Vec::new();
+    for (turn, number) in draw.iter().enumerate() {
+        let before = wins.len();
+        for (i, coord) in cells.get(number).into_iter().flatten() {
+            let card = &mut cards[*i];
+            if !card.won && card.mark(&boards[*i], *coord, rules) {
+                card.won = true;
+                wins.push(Win {
+                    board: *i,
+                    turn,
+                    number: *number,
+                    score: card.unmarked_sum * number,
+                });
+            }
+        }
+        if rules.ties == Ties::Reject && wins.len() - before > 1 {
+            let boards = wins[before..].iter().map(|win| win.board).collect();
+            return Err(RulesError::Tie { turn, boards });
+        }
+        if wins.len() == boards.len() {
+            break;
+        }
+    }
+    Ok(wins)
+}
+
+/// Play by the rules of the puzzle, which suit any boards.
+fn play_puzzle(input: &[BingoGrid], draw: &[u32]) -> Vec<Win> {
+    play(input, draw, &Rules::new()).unwrap_or_else(|e| panic!("Failed to play: {}", e))
+}
+
+fn part_one(input: &[BingoGrid], draw: &[u32]) -> u32 {
+    play_puzzle(input, draw).first().map_or(0, |win| win.score)
+}
+
+fn part_two(input: &[BingoGrid], draw: &[u32]) -> u32 {
+    play_puzzle(input, draw).last().map_or(0, |win| win.score)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = (Vec<BingoGrid>, Vec<u32>);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (draw_input, bingo_input) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(
+                input,
+                "Expected the draw and grids separated by a blank line",
+            )
+        })?;
+        // Skip the empty grids a trailing blank line would otherwise give.
+        let grids = try_parse_input_with(bingo_input, |s| {
+            s.split("\n\n").filter(|grid| !grid.trim().is_empty())
+        })
+        .map_err(|e| e.offset_by(input, draw_input.len() + 2))?;
+        let draw = try_parse_input_with(draw_input, |s| s.split(','))?;
+        Ok((grids, draw))
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(&input.0, &input.1)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7"
+            .to_string();
+
+        let (inputs, draw) = Day04::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs, &draw), 4512);
+        assert_eq!(part_two(&inputs, &draw), 1924);
+
+        let wins = play_puzzle(&inputs, &draw);
+        let order: Vec<_> = wins.iter().map(|win| (win.board, win.number)).collect();
+        assert_eq!(order, [(2, 24), (0, 16), (1, 13)]);
+        assert_eq!(wins[0].turn, 11);
+
+        // Trailing blank lines don't make an empty grid.
+        for end in ["\n", "\n\n", "\n\n\n"] {
+            let (grids, _) = Day04::parse(&format!("{}{}", input, end)).unwrap();
+            assert_eq!(grids, inputs);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let e = "1 2\n3 4 5".parse::<BingoGrid>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "3 4 5"));
+
+        let e = " 1  2\n12  1".parse::<BingoGrid>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "1"));
+        assert!("".parse::<BingoGrid>().is_err());
+    }
+
+    #[test]
+    fn test_rules() {
+        let square: BingoGrid = "1 2 3\n4 5 6\n7 8 9".parse().unwrap();
+        let wide: BingoGrid = "1 2 3 4\n5 6 7 8".parse().unwrap();
+        let boards = [square, wide];
+        let draw = [7, 5, 3, 8, 6, 2, 1, 4, 9];
+        let order = |rules: &Rules| -> Vec<(usize, u32)> {
+            let wins = play(&boards, &draw, rules).unwrap();
+            wins.iter().map(|win| (win.board, win.number)).collect()
+        };
+
+        // The wide board wins on its column of 3 and 7, or its row of 5 to 8,
+        // while the square board wins on its column of 2, 5 and 8, or its row
+        // of 1 to 3.
+        assert_eq!(order(&Rules::new()), [(1, 3), (0, 2)]);
+        assert_eq!(order(&Rules::new().columns(false)), [(1, 6), (0, 1)]);
+        assert_eq!(order(&Rules::new().full_house(true)), [(1, 4), (0, 9)]);
+
+        // Diagonals need square boards, where 7, 5 then 3 wins.
+        let rules = Rules::new().diagonals(true);
+        assert_eq!(
+            play(&boards, &draw, &rules),
+            Err(RulesError::NotSquare {
+                board: 1,
+                width: 4,
+                height: 2
+            })
+        );
+        let wins = play(&boards[..1], &draw, &rules).unwrap();
+        assert_eq!((wins[0].number, wins[0].score), (3, 30 * 3));
+
+        let rules = Rules::new().rows(false).columns(false);
+        assert_eq!(play(&boards, &draw, &rules), Err(RulesError::NoWayToWin));
+    }
+
+    #[test]
+    fn test_ties() {
+        let boards: Vec<BingoGrid> = ["1 2\n3 4", "2 1\n5 6", "5 3\n1 6"]
+            .iter()
+            .map(|board| board.parse().unwrap())
+            .collect();
+        let turns = |draw: &[u32], rules: &Rules| -> Vec<(usize, usize)> {
+            let wins = play(&boards, draw, rules).unwrap();
+            wins.iter().map(|win| (win.board, win.turn)).collect()
+        };
+
+        // The first two boards both win on 2, then the last on 5.
+        let draw = [1, 2, 5];
+        assert_eq!(turns(&draw, &Rules::new()), [(0, 1), (1, 1), (2, 2)]);
+        assert_eq!(
+            play(&boards, &draw, &Rules::new().ties(Ties::Reject)),
+            Err(RulesError::Tie {
+                turn: 1,
+                boards: vec![0, 1]
+            })
+        );
+
+        // Without ties, rejecting them changes nothing.
+        let draw = [3, 5, 1, 2];
+        let rules = Rules::new().ties(Ties::Reject);
+        assert_eq!(turns(&draw, &rules), [(2, 1), (0, 2), (1, 3)]);
+        assert_eq!(turns(&draw, &rules), turns(&draw, &Rules::new()));
+    }
+}
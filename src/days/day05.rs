@@ -1,33 +1,41 @@
 /// Solution to Advent of Code Challenge Day 05.
-use aoc2021::{get_day_input, parse_input_lines, parse_input_with, print_elapsed_time};
-use std::num::ParseIntError;
+use crate::{try_parse_input_lines, try_parse_input_with, ParseError, Solution};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Point(u32, u32);
 
 impl FromStr for Point {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parsed: Vec<_> = parse_input_with(s, |s| s.split(','));
-        Ok(Self(parsed[0], parsed[1]))
+        let parsed: Vec<_> = try_parse_input_with(s, |s| s.split(','))?;
+        match parsed[..] {
+            [x, y] => Ok(Self(x, y)),
+            _ => Err(ParseError::new(
+                s,
+                "Point should be two numbers separated by ','",
+            )),
+        }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Line {
+pub struct Line {
     start: Point,
     end: Point,
 }
 
 impl FromStr for Line {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parsed: Vec<_> = parse_input_with(s, |s| s.split(" -> "));
-        Ok(Self {
-            start: parsed[0],
-            end: parsed[1],
-        })
+        let parsed: Vec<_> = try_parse_input_with(s, |s| s.split(" -> "))?;
+        match parsed[..] {
+            [start, end] => Ok(Self { start, end }),
+            _ => Err(ParseError::new(
+                s,
+                "Line should be two points separated by ' -> '",
+            )),
+        }
     }
 }
 
@@ -123,7 +131,7 @@ fn calculate_overlaps(input: Vec<Line>) -> u64 {
     overlaps
 }
 
-fn part_one(input: &Vec<Line>) -> u64 {
+fn part_one(input: &[Line]) -> u64 {
     calculate_overlaps(
         input
             .iter()
@@ -133,16 +141,29 @@ fn part_one(input: &Vec<Line>) -> u64 {
     )
 }
 
-fn part_two(input: &Vec<Line>) -> u64 {
+fn part_two(input: &[Line]) -> u64 {
     calculate_overlaps(input.to_vec())
 }
-fn main() {
-    let input = get_day_input("05");
-    let inputs = parse_input_lines(&input);
-    println!("Day 05:");
-    println!("==========");
-    println!("Part one: {}", print_elapsed_time(|| part_one(&inputs)));
-    println!("Part two: {}", print_elapsed_time(|| part_two(&inputs)));
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_lines(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -163,7 +184,7 @@ mod tests {
 5,5 -> 8,2"
             .to_string();
 
-        let inputs = parse_input_lines(&input);
+        let inputs = Day05::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&inputs), 5);
         assert_eq!(part_two(&inputs), 12);
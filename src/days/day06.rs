@@ -1,5 +1,5 @@
 /// Solution to Advent of Code Challenge Day 06.
-use aoc2021::{get_day_input, parse_input_with, print_elapsed_time};
+use crate::{try_parse_input_with, ParseError, Solution};
 
 fn calculate_population(starting: &[u32], cycles: u32) -> u64 {
     let mut counts = [0u64; 9];
@@ -33,13 +33,26 @@ fn part_two(input: &[u32]) -> u64 {
     calculate_population(input, 256)
 }
 
-fn main() {
-    let input = get_day_input("06");
-    let inputs: Vec<_> = parse_input_with(&input, |s| s.split(','));
-    println!("Day 06:");
-    println!("==========");
-    println!("Part one: {}", print_elapsed_time(|| part_one(&inputs)));
-    println!("Part two: {}", print_elapsed_time(|| part_two(&inputs)));
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u32>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_with(input, |s| s.split(','))
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -50,7 +63,7 @@ mod tests {
     fn test_given_example() {
         let input: String = "3,4,3,1,2".to_string();
 
-        let inputs: Vec<_> = parse_input_with(&input, |s| s.split(','));
+        let inputs = Day06::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&inputs), 5934);
         assert_eq!(part_two(&inputs), 26984457539);
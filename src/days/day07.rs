@@ -1,5 +1,5 @@
 /// Solution to Advent of Code Challenge Day 07.
-use aoc2021::{get_day_input, parse_input_with, print_elapsed_time};
+use crate::{try_parse_input_with, ParseError, Solution};
 
 fn cost_p1(target: u32, start: u32) -> u32 {
     let a: i32 = start.try_into().unwrap();
@@ -54,13 +54,26 @@ fn part_two(input: &[u32]) -> u32 {
         .unwrap()
 }
 
-fn main() {
-    let input = get_day_input("07");
-    let inputs: Vec<_> = parse_input_with(&input, |s| s.split(','));
-    println!("Day 07:");
-    println!("==========");
-    println!("Part one: {}", print_elapsed_time(|| part_one(&inputs)));
-    println!("Part two: {}", print_elapsed_time(|| part_two(&inputs)));
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_with(input, |s| s.split(','))
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -71,7 +84,7 @@ mod tests {
     fn test_given_example() {
         let input: String = "16,1,2,0,4,2,7,1,2,14".to_string();
 
-        let inputs: Vec<_> = parse_input_with(&input, |s| s.split(','));
+        let inputs = Day07::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&inputs), 37);
         assert_eq!(part_two(&inputs), 168);
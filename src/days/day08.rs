@@ -1,11 +1,8 @@
 /// Solution to Advent of Code Challenge Day 08.
-use aoc2021::{get_day_input, parse_input_lines, parse_input_with, print_elapsed_time};
+use crate::{try_parse_input_lines, try_parse_input_with, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
-use std::num::ParseIntError;
 use std::str::FromStr;
 
-const DAY: &str = "08";
-
 const A: u32 = 0x01;
 const B: u32 = 0x02;
 const C: u32 = 0x04;
@@ -29,26 +26,34 @@ const NINE: u32 = A | B | C | D | F | G;
 struct Digit(HashSet<char>);
 
 impl FromStr for Digit {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.chars().collect()))
+        match s.find(|ch| !('a'..='g').contains(&ch)) {
+            Some(i) => {
+                Err(ParseError::new(&s[i..], "Wires should be from 'a' to 'g'").offset_by(s, i))
+            }
+            None => Ok(Self(s.chars().collect())),
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
-struct Display {
+pub struct Display {
     notes: Vec<Digit>,
     digits: Vec<Digit>,
 }
 
 impl FromStr for Display {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parsed: Vec<String> = parse_input_with(s, |s| s.split(" | "));
+        let (notes, digits) = s
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::new(s, "Notes and digits should be separated by ' | '"))?;
 
         Ok(Self {
-            notes: parse_input_with(&parsed[0], |s| s.split(' ')),
-            digits: parse_input_with(&parsed[1], |s| s.split(' ')),
+            notes: try_parse_input_with(notes, |s| s.split(' '))?,
+            digits: try_parse_input_with(digits, |s| s.split(' '))
+                .map_err(|e| e.offset_by(s, notes.len() + 3))?,
         })
     }
 }
@@ -194,8 +199,7 @@ impl Display {
 fn part_one(input: &[Display]) -> u32 {
     input
         .iter()
-        .map(|d| &d.digits)
-        .flatten()
+        .flat_map(|d| &d.digits)
         .map(|d| match d.0.len() {
             // These number of signals produce unique numbers
             2 | 3 | 4 | 7 => 1,
@@ -212,13 +216,26 @@ fn part_two(input: &[Display]) -> u32 {
     sum
 }
 
-fn main() {
-    let input = get_day_input(DAY);
-    let inputs: Vec<_> = parse_input_lines(&input);
-    println!("Day {}:", DAY);
-    println!("==========");
-    println!("Part one: {}", print_elapsed_time(|| part_one(&inputs)));
-    println!("Part two: {}", print_elapsed_time(|| part_two(&inputs)));
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Display>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_lines(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -240,7 +257,7 @@ egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
 gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"
                 .to_string();
 
-        let inputs: Vec<_> = parse_input_lines(&input);
+        let inputs = Day08::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&inputs), 26);
         assert_eq!(part_two(&inputs), 61229);
@@ -0,0 +1,94 @@
+/// Solution to Advent of Code Challenge Day 09.
+use crate::grid::{Coord, Grid};
+use crate::{ParseError, Solution};
+use std::collections::HashSet;
+
+type Height = u32;
+
+fn get_lowest(input: &Grid<Height>) -> Vec<Coord> {
+    input
+        .iter()
+        .filter(|(point, val)| input.neighbours4(*point).all(|n| *val < &input[n]))
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn part_one(input: &Grid<Height>) -> u32 {
+    get_lowest(input).iter().map(|p| input[*p] + 1).sum()
+}
+
+fn part_two(input: &Grid<Height>) -> u32 {
+    let lowest = get_lowest(input);
+    let mut basin_sizes = Vec::new();
+
+    // Algorithm for finding basins:
+    // - From each lowest point, form hashset of coords representing the basin
+    // - Add all neighbours of lowest point to hashset which aren't a 9
+    // - For each neighbour which is not a 9 and not already in the hashset,
+    //   find its neighbours and repeat
+    // - Each lowest point is associated with a single basin, so this is all the
+    //   basins
+    for point in lowest {
+        let mut basin = HashSet::new();
+        basin.insert(point);
+
+        let mut remaining = vec![point];
+
+        while let Some(curr) = remaining.pop() {
+            let new_basin_neighbours: Vec<_> = input
+                .neighbours4(curr)
+                .filter(|p| input[*p] != 9 && !basin.contains(p))
+                .collect();
+
+            basin.extend(new_basin_neighbours.iter());
+            remaining.extend(new_basin_neighbours.iter());
+        }
+
+        basin_sizes.push(basin.len() as u32);
+    }
+
+    basin_sizes.sort_unstable();
+    basin_sizes.iter().rev().take(3).product()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Grid<Height>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678"
+            .to_string();
+
+        let inputs = Day09::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs), 15);
+        assert_eq!(part_two(&inputs), 1134);
+    }
+}
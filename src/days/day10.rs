@@ -1,10 +1,7 @@
 /// Solution to Advent of Code Challenge Day 10.
-use aoc2021::{get_day_input, parse_input_lines, print_elapsed_time};
-use std::num::ParseIntError;
+use crate::{try_parse_input_lines, try_parse_input_with, ParseError, Solution};
 use std::str::FromStr;
 
-const DAY: &str = "10";
-
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Bracket {
     OpenNormal,
@@ -18,7 +15,7 @@ enum Bracket {
 }
 
 impl FromStr for Bracket {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "(" => Self::OpenNormal,
@@ -29,7 +26,7 @@ impl FromStr for Bracket {
             "}" => Self::CloseCurly,
             "<" => Self::OpenAngle,
             ">" => Self::CloseAngle,
-            _ => panic!("Invalid bracket detected"),
+            _ => return Err(ParseError::new(s, "Invalid bracket detected")),
         })
     }
 }
@@ -47,16 +44,14 @@ impl Bracket {
 }
 
 #[derive(Debug, Clone)]
-struct Line(Vec<Bracket>);
+pub struct Line(Vec<Bracket>);
 
 impl FromStr for Line {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.chars()
-                .map(|ch| ch.to_string().parse().unwrap())
-                .collect(),
-        ))
+        Ok(Self(try_parse_input_with(s, |s| {
+            s.split("").filter(|s| !s.is_empty())
+        })?))
     }
 }
 
@@ -148,13 +143,26 @@ fn part_two(input: &[Line]) -> u64 {
     scores[scores.len() / 2]
 }
 
-fn main() {
-    let input = get_day_input(DAY);
-    let inputs: Vec<_> = parse_input_lines(&input);
-    println!("Day {}:", DAY);
-    println!("==========");
-    println!("Part one: {}", print_elapsed_time(|| part_one(&inputs)));
-    println!("Part two: {}", print_elapsed_time(|| part_two(&inputs)));
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Line>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        try_parse_input_lines(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -175,7 +183,7 @@ mod tests {
 <{([{{}}[<[[[<>{}]]]>[]]"
             .to_string();
 
-        let inputs: Vec<_> = parse_input_lines(&input);
+        let inputs = Day10::parse(&input).unwrap();
         // Check each gives the right answer.
         assert_eq!(part_one(&inputs), 26397);
         assert_eq!(part_two(&inputs), 288957);
@@ -0,0 +1,130 @@
+/// Solution to Advent of Code Challenge Day 11.
+use crate::grid::Grid;
+use crate::{ParseError, Solution};
+use std::collections::HashSet;
+
+type Power = u32;
+
+// Iterate the octopi one step and return how many flashed.
+fn octopus_iteration(octopi: &mut Grid<Power>) -> u64 {
+    octopi.values_mut().for_each(|o| *o += 1);
+
+    let mut flashing = HashSet::new();
+    let mut processing = Vec::new();
+
+    // Setup the processing with every octopus now flashing
+    for (point, octopus) in octopi.iter() {
+        if *octopus > 9 {
+            processing.push(point);
+            flashing.insert(point);
+        }
+    }
+
+    // Then for all octopi that flashed, increase the power level of the
+    // neighbour octopi, which may trigger more flashing. Process until the
+    // processing queue is empty.
+    while let Some(octopus) = processing.pop() {
+        let neighbours: Vec<_> = octopi.neighbours8(octopus).collect();
+        for point in neighbours {
+            octopi[point] += 1;
+            // If this has enough energy to flash but that flash hasn't
+            // already been processed, add it to the processing queue.
+            if octopi[point] > 9 && !flashing.contains(&point) {
+                processing.push(point);
+                flashing.insert(point);
+            }
+        }
+    }
+
+    // Set any octopus that flashed to energy 0.
+    for point in &flashing {
+        octopi[*point] = 0;
+    }
+
+    flashing.len() as u64
+}
+
+fn part_one(input: &Grid<Power>, steps: usize) -> u64 {
+    let mut octopi = input.clone();
+    let mut flashes = 0;
+
+    for _ in 0..steps {
+        flashes += octopus_iteration(&mut octopi);
+    }
+
+    flashes
+}
+
+fn part_two(input: &Grid<Power>) -> u64 {
+    let mut octopi = input.clone();
+    let mut flashes = 0;
+    let mut steps = 0;
+
+    while flashes != octopi.len() as u64 {
+        steps += 1;
+        flashes = octopus_iteration(&mut octopi);
+    }
+
+    steps
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Grid<Power>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input, 100)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"
+            .to_string();
+
+        let inputs = Day11::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs, 100), 1656);
+        assert_eq!(part_two(&inputs), 195);
+    }
+
+    #[test]
+    fn test_other_example() {
+        let input: String = "11111
+19991
+19191
+19991
+11111"
+            .to_string();
+
+        let inputs = Day11::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs, 2), 9);
+    }
+}
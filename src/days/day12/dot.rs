@@ -0,0 +1,67 @@
+/// Writing a cave system as a Graphviz graph in the DOT language.
+use super::{CaveId, CaveSystem};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Colour of the caves and passages along a highlighted path.
+const HIGHLIGHT: &str = "red";
+
+/// Get the attributes to draw a cave with, by what kind of cave it is.
+fn cave_style(system: &CaveSystem, cave: CaveId) -> &'static str {
+    if Some(cave) == system.start() {
+        "shape=doublecircle, style=filled, fillcolor=palegreen"
+    } else if Some(cave) == system.end() {
+        "shape=doublecircle, style=filled, fillcolor=lightpink"
+    } else if system.is_small(cave) {
+        "shape=circle"
+    } else {
+        "shape=box, style=filled, fillcolor=lightgrey"
+    }
+}
+
+/// Write the caves and passages as an undirected graph, highlighting the
+/// caves and passages along a path if one is given.
+pub fn to_dot(system: &CaveSystem, path: Option<&[CaveId]>) -> String {
+    let path = path.unwrap_or_default();
+    let on_path: HashSet<CaveId> = path.iter().copied().collect();
+    let passages: HashSet<(CaveId, CaveId)> = path
+        .windows(2)
+        .map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1])))
+        .collect();
+
+    let mut dot = String::from("graph caves {\n");
+    for cave in 0..system.names().len() {
+        let highlight = match on_path.contains(&cave) {
+            true => format!(", color={}, penwidth=3", HIGHLIGHT),
+            false => String::new(),
+        };
+        writeln!(
+            dot,
+            "    {:?} [{}{}];",
+            system.names().name(cave),
+            cave_style(system, cave),
+            highlight
+        )
+        .unwrap();
+    }
+
+    for from in 0..system.names().len() {
+        // Each passage leads both ways but is only drawn once.
+        for &to in system.leads_to(from).iter().filter(|to| **to > from) {
+            let highlight = match passages.contains(&(from, to)) {
+                true => format!(" [color={}, penwidth=3]", HIGHLIGHT),
+                false => String::new(),
+            };
+            writeln!(
+                dot,
+                "    {:?} -- {:?}{};",
+                system.names().name(from),
+                system.names().name(to),
+                highlight
+            )
+            .unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
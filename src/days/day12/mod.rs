@@ -0,0 +1,423 @@
+/// Solution to Advent of Code Challenge Day 12.
+use crate::{ParseError, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+mod dot;
+mod paths;
+mod policy;
+
+pub use dot::to_dot;
+pub use paths::{count_paths, list_paths};
+pub use policy::Policy;
+
+/// Dense id of a cave, given in the order caves are first seen.
+pub type CaveId = usize;
+
+/// Gives each distinct name a dense id, so caves can be looked up by index.
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    ids: HashMap<String, CaveId>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the id of a name, giving it the next id if it hasn't got one.
+    pub fn intern(&mut self, name: &str) -> CaveId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    pub fn get(&self, name: &str) -> Option<CaveId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Get the name with an id.
+    ///
+    /// Panics if the id wasn't given by this interner.
+    pub fn name(&self, id: CaveId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Small caves are named in lower case, and big caves in upper case.
+fn is_small(name: &str) -> bool {
+    name != name.to_uppercase()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    from: String,
+    to: String,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(s, "Entry should be two caves separated by '-'"))?;
+        for (name, offset) in [(from, 0), (to, from.len() + 1)] {
+            if name.is_empty() || !name.chars().all(char::is_alphanumeric) {
+                return Err(
+                    ParseError::new(name, "Cave name should be letters or digits")
+                        .offset_by(s, offset),
+                );
+            }
+        }
+        Ok(Self {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
+
+/// The caves and the passages between them, which lead both ways.
+#[derive(Debug, Clone)]
+pub struct CaveSystem {
+    names: Interner,
+    /// Caves each cave leads to, indexed by id.
+    leads_to: Vec<Vec<CaveId>>,
+    /// Whether each cave is small, indexed by id.
+    small: Vec<bool>,
+}
+
+impl CaveSystem {
+    pub fn names(&self) -> &Interner {
+        &self.names
+    }
+
+    pub fn is_small(&self, cave: CaveId) -> bool {
+        self.small[cave]
+    }
+
+    pub fn leads_to(&self, cave: CaveId) -> &[CaveId] {
+        &self.leads_to[cave]
+    }
+
+    /// Get the cave paths start from, if there is one.
+    pub fn start(&self) -> Option<CaveId> {
+        self.names.get("start")
+    }
+
+    /// Get the cave paths end at, if there is one.
+    pub fn end(&self) -> Option<CaveId> {
+        self.names.get("end")
+    }
+
+    /// Get the names of the caves along a path, separated by commas.
+    pub fn path_names(&self, path: &[CaveId]) -> String {
+        let names: Vec<&str> = path.iter().map(|cave| self.names.name(*cave)).collect();
+        names.join(",")
+    }
+
+    /// Get the caves along a path from their names separated by commas,
+    /// checking each leads to the next.
+    pub fn parse_path(&self, names: &str) -> Result<Vec<CaveId>, String> {
+        let path = names
+            .split(',')
+            .map(|name| {
+                self.names
+                    .get(name)
+                    .ok_or_else(|| format!("There is no cave named {:?}", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for pair in path.windows(2) {
+            if !self.leads_to(pair[0]).contains(&pair[1]) {
+                return Err(format!(
+                    "Cave {} doesn't lead to {}",
+                    self.names.name(pair[0]),
+                    self.names.name(pair[1])
+                ));
+            }
+        }
+        Ok(path)
+    }
+
+    fn add(&mut self, name: &str) -> CaveId {
+        let id = self.names.intern(name);
+        if id == self.leads_to.len() {
+            self.leads_to.push(Vec::new());
+            self.small.push(is_small(name));
+        }
+        id
+    }
+}
+
+fn get_cave_system(input: &str) -> Result<CaveSystem, ParseError> {
+    let mut system = CaveSystem {
+        names: Interner::new(),
+        leads_to: Vec::new(),
+        small: Vec::new(),
+    };
+
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+
+        let entry: Entry = line
+            .parse()
+            .map_err(|e: ParseError| e.offset_by(input, line_offset))?;
+        if !is_small(&entry.from) && !is_small(&entry.to) {
+            let reason = "Big caves lead to each other, so paths could go on forever";
+            return Err(ParseError::new(line, reason).offset_by(input, line_offset));
+        }
+
+        let from = system.add(&entry.from);
+        let to = system.add(&entry.to);
+        if !system.leads_to[from].contains(&to) {
+            system.leads_to[from].push(to);
+            system.leads_to[to].push(from);
+        }
+    }
+    Ok(system)
+}
+
+fn part_one(input: &CaveSystem) -> u64 {
+    count_paths(input, &Policy::new()).expect("Too many paths to count")
+}
+
+fn part_two(input: &CaveSystem) -> u64 {
+    count_paths(input, &Policy::new().single_cave(2)).expect("Too many paths to count")
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = CaveSystem;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_cave_system(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+
+    /// Write the caves as a Graphviz graph, optionally highlighting a path.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        let path = match args {
+            [] => None,
+            [flag, value] if flag == "--path" => Some(input.parse_path(value)?),
+            _ => return Err("Expected only --path CAVE,CAVE,... for day 12".to_string()),
+        };
+        Ok(to_dot(input, path.as_deref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end"
+            .to_string();
+
+        let inputs = Day12::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs), 10);
+        assert_eq!(part_two(&inputs), 36);
+    }
+
+    #[test]
+    fn test_other_example() {
+        let input: String = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc"
+            .to_string();
+
+        let inputs = Day12::parse(&input).unwrap();
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs), 19);
+        assert_eq!(part_two(&inputs), 103);
+
+        // One revisit in total is the same as one cave twice.
+        let policy = Policy::new().total_revisits(1);
+        assert_eq!(count_paths(&inputs, &policy), Some(103));
+    }
+
+    #[test]
+    fn test_long_names() {
+        // Names which collided when packed into 16 bits.
+        let input = "start-abc\nstart-abcd\nabc-end\nabcd-end\nabc-ABC\nABC-end";
+        let system = Day12::parse(input).unwrap();
+        assert_eq!(system.names().len(), 5);
+        assert_eq!(system.names().name(1), "abc");
+        assert!(!system.is_small(system.names().get("ABC").unwrap()));
+
+        // start-abc-end, start-abc-ABC-end, start-abcd-end.
+        assert_eq!(part_one(&system), 3);
+
+        // Any number of small caves, here in a chain with a big cave beside
+        // each one to double back through.
+        let mut input = "start-c0\nc99-end\n".to_string();
+        for i in 0..99 {
+            input.push_str(&format!("c{}-c{}\nc{}-B{}\n", i, i + 1, i, i));
+        }
+        let system = Day12::parse(&input).unwrap();
+        assert_eq!(part_one(&system), 1);
+        assert_eq!(part_two(&system), 100);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let e = Day12::parse("start-A\nA-B\nB-end").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "A-B"));
+
+        let e = Day12::parse("start-A\nA-\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+    }
+
+    #[test]
+    fn test_many_paths() {
+        // A big cave leading to 12 small caves, so paths visit any ordered
+        // selection of them, of which there are e * 12! rounded down.
+        let mut input = "start-X\nX-end\n".to_string();
+        for i in 0..12 {
+            input.push_str(&format!("X-c{}\n", i));
+        }
+        let system = Day12::parse(&input).unwrap();
+        assert_eq!(part_one(&system), 1_302_061_345);
+    }
+
+    #[test]
+    fn test_policies() {
+        let input = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
+        let system = Day12::parse(input).unwrap();
+        let names = |policy: &Policy| -> Vec<String> {
+            let mut paths: Vec<String> = list_paths(&system, policy)
+                .iter()
+                .map(|path| system.path_names(path))
+                .collect();
+            paths.sort();
+            paths
+        };
+
+        // Counting should agree with listing for every kind of policy.
+        let policies = [
+            (Policy::new(), Some(10)),
+            (Policy::new().single_cave(2), Some(36)),
+            (Policy::new().single_cave(3), None),
+            (Policy::new().single_cave(2).total_revisits(0), Some(10)),
+            (Policy::new().total_revisits(1), Some(36)),
+            (Policy::new().total_revisits(2), None),
+            (
+                Policy::new().limit("b", 2).limit("c", 2).total_revisits(1),
+                None,
+            ),
+            (Policy::new().limit("A", 1), None),
+            (Policy::new().forbid("A"), Some(1)),
+            (Policy::new().forbid("end"), Some(0)),
+        ];
+        for (policy, count) in &policies {
+            let listed = names(policy).len() as u64;
+            assert_eq!(count_paths(&system, policy), Some(listed), "{:?}", policy);
+            if let Some(count) = count {
+                assert_eq!(listed, *count, "{:?}", policy);
+            }
+        }
+
+        assert_eq!(names(&Policy::new().forbid("A")), ["start,b,end"]);
+        // Revisits can be shared between caves, or all go to one.
+        let shared = names(&Policy::new().total_revisits(2));
+        assert!(shared.contains(&"start,A,b,A,b,A,c,A,c,A,end".to_string()));
+        assert!(shared.contains(&"start,A,c,A,c,A,c,A,end".to_string()));
+        assert!(names(&Policy::new().total_revisits(3)).len() > shared.len());
+        assert_eq!(
+            names(&Policy::new().limit("A", 1).forbid("c")),
+            [
+                "start,A,b,end",
+                "start,A,end",
+                "start,b,A,end",
+                "start,b,end"
+            ]
+        );
+        assert_eq!(
+            names(&Policy::new().forbid("b").single_cave(2)),
+            ["start,A,c,A,c,A,end", "start,A,c,A,end", "start,A,end"]
+        );
+    }
+
+    #[test]
+    fn test_large_limits() {
+        // Paths too long to follow by recursion.
+        let system = Day12::parse("start-A\nA-b\nA-end").unwrap();
+        let policy = Policy::new().limit("b", 100_000);
+        assert_eq!(count_paths(&system, &policy), Some(100_001));
+
+        // Paths visit b and c in any order up to n times each, of which there
+        // are (2n + 2 choose n + 1) - 1.
+        let system = Day12::parse("start-A\nA-b\nA-c\nA-end").unwrap();
+        let policy = |n| Policy::new().limit("b", n).limit("c", n);
+        assert_eq!(count_paths(&system, &policy(20)), Some(538_257_874_439));
+        assert_eq!(count_paths(&system, &policy(40)), None);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let system = Day12::parse("start-A\nA-b\nA-end\nb-end").unwrap();
+        let path = system.parse_path("start,A,b,end").unwrap();
+        assert_eq!(
+            to_dot(&system, Some(&path)),
+            r#"graph caves {
+    "start" [shape=doublecircle, style=filled, fillcolor=palegreen, color=red, penwidth=3];
+    "A" [shape=box, style=filled, fillcolor=lightgrey, color=red, penwidth=3];
+    "b" [shape=circle, color=red, penwidth=3];
+    "end" [shape=doublecircle, style=filled, fillcolor=lightpink, color=red, penwidth=3];
+    "start" -- "A" [color=red, penwidth=3];
+    "A" -- "b" [color=red, penwidth=3];
+    "A" -- "end";
+    "b" -- "end" [color=red, penwidth=3];
+}
+"#
+        );
+        assert!(!to_dot(&system, None).contains("color=red"));
+
+        assert!(system.parse_path("start,b").is_err());
+        assert!(system.parse_path("start,C").is_err());
+    }
+}
@@ -0,0 +1,232 @@
+/// Counting and listing the paths through a cave system under a policy.
+use super::{CaveId, CaveSystem, Policy};
+use std::collections::HashMap;
+
+/// Where a path has got to, which is all that decides how it can carry on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    cave: CaveId,
+    /// Visits to each cave with limited visits so far, indexed by its slot.
+    visits: Vec<usize>,
+    /// The small cave chosen to be visited more than its limit, if any.
+    chosen: Option<CaveId>,
+    /// Visits to small caves after the first to each, in total.
+    revisits: usize,
+}
+
+/// A policy applied to the caves of a particular system.
+struct Rules<'a> {
+    system: &'a CaveSystem,
+    start: CaveId,
+    end: CaveId,
+    /// Most visits to each cave, or `None` for any number.
+    limits: Vec<Option<usize>>,
+    /// Index of each cave with limited visits in the visits of a state.
+    slots: Vec<Option<usize>>,
+    single_cave: usize,
+    total_revisits: usize,
+}
+
+impl<'a> Rules<'a> {
+    /// Apply a policy to a system, unless it has no start or end.
+    fn new(system: &'a CaveSystem, policy: &Policy) -> Option<Self> {
+        let names = system.names();
+        let (start, end) = (system.start()?, system.end()?);
+        let limits: Vec<Option<usize>> = (0..names.len())
+            .map(|cave| match policy.limits.get(names.name(cave)) {
+                Some(limit) => Some(*limit),
+                None if !system.is_small(cave) => None,
+                // A total number of revisits lets the other small caves be
+                // revisited as often as it allows, which it keeps track of.
+                None if policy.total_revisits.is_some() && cave != start && cave != end => {
+                    Some(usize::MAX)
+                }
+                None => Some(1),
+            })
+            .collect();
+        let mut slot_count = 0;
+        let slots = limits
+            .iter()
+            .map(|limit| {
+                limit.map(|_| {
+                    slot_count += 1;
+                    slot_count - 1
+                })
+            })
+            .collect();
+
+        Some(Self {
+            system,
+            start,
+            end,
+            limits,
+            slots,
+            single_cave: policy.single_cave,
+            total_revisits: policy.total_revisits.unwrap_or(usize::MAX),
+        })
+    }
+
+    /// Get the state of a path which has only visited start, if it can.
+    fn start_state(&self) -> Option<State> {
+        let state = State {
+            cave: self.start,
+            visits: vec![0; self.slots.iter().flatten().count()],
+            chosen: None,
+            revisits: 0,
+        };
+        self.enter(&state, self.start)
+    }
+
+    /// Get the state of a path after moving on to a cave, if it's allowed to.
+    fn enter(&self, state: &State, cave: CaveId) -> Option<State> {
+        let mut next = State {
+            cave,
+            ..state.clone()
+        };
+        let Some(slot) = self.slots[cave] else {
+            return Some(next);
+        };
+
+        next.visits[slot] += 1;
+        let visits = next.visits[slot];
+        let small = self.system.is_small(cave);
+        let limit = self.limits[cave].unwrap_or(usize::MAX);
+        if limit == 0 {
+            // Forbidden caves can't be chosen to go over their limit.
+            return None;
+        }
+        if visits > limit {
+            // Only one small cave other than start and end can go over.
+            let can_choose = small && cave != self.start && cave != self.end;
+            if !can_choose || visits > self.single_cave || state.chosen.is_some_and(|c| c != cave) {
+                return None;
+            }
+            next.chosen = Some(cave);
+        }
+        if small && visits > 1 {
+            next.revisits += 1;
+            if next.revisits > self.total_revisits {
+                return None;
+            }
+        }
+        Some(next)
+    }
+
+    /// Get the states a path can move on to, where no path returns to start,
+    /// in the reverse order of the caves they lead to so they can be popped.
+    fn moves(&self, state: &State) -> Vec<State> {
+        self.system
+            .leads_to(state.cave)
+            .iter()
+            .rev()
+            .filter(|cave| **cave != self.start)
+            .filter_map(|cave| self.enter(state, *cave))
+            .collect()
+    }
+
+    /// Count the paths from a state to the end, remembering the count for
+    /// each state since many paths reach the same state, or `None` if there
+    /// are too many to count.
+    ///
+    /// Paths are followed with a stack of their own rather than recursion, as
+    /// large limits make paths too long for the call stack.
+    fn count_from(&self, state: State) -> Option<u64> {
+        /// A state whose count is being found, along with the moves from it
+        /// still to be counted.
+        struct Frame {
+            state: State,
+            moves: Vec<State>,
+            count: u64,
+        }
+
+        let mut counts: HashMap<State, u64> = HashMap::new();
+        let mut stack = vec![Frame {
+            moves: self.moves(&state),
+            state,
+            count: 0,
+        }];
+        while let Some(mut frame) = stack.pop() {
+            let Some(next) = frame.moves.pop() else {
+                // Every move from the state has been counted.
+                match stack.last_mut() {
+                    Some(parent) => parent.count = parent.count.checked_add(frame.count)?,
+                    None => return Some(frame.count),
+                }
+                counts.insert(frame.state, frame.count);
+                continue;
+            };
+
+            let known = if next.cave == self.end {
+                Some(1)
+            } else {
+                counts.get(&next).copied()
+            };
+            match known {
+                Some(count) => {
+                    frame.count = frame.count.checked_add(count)?;
+                    stack.push(frame);
+                }
+                None => {
+                    let moves = self.moves(&next);
+                    stack.push(frame);
+                    stack.push(Frame {
+                        state: next,
+                        moves,
+                        count: 0,
+                    });
+                }
+            }
+        }
+        unreachable!("The first state is always the last counted")
+    }
+
+    /// List the paths from a state to the end, following them with a stack
+    /// of their own like `count_from`.
+    fn list_from(&self, state: State) -> Vec<Vec<CaveId>> {
+        let mut paths = Vec::new();
+        let mut path = vec![state.cave];
+        let mut stack = vec![self.moves(&state)];
+        while let Some(moves) = stack.last_mut() {
+            match moves.pop() {
+                Some(next) if next.cave == self.end => {
+                    path.push(next.cave);
+                    paths.push(path.clone());
+                    path.pop();
+                }
+                Some(next) => {
+                    path.push(next.cave);
+                    stack.push(self.moves(&next));
+                }
+                None => {
+                    stack.pop();
+                    path.pop();
+                }
+            }
+        }
+        paths
+    }
+}
+
+/// Count the paths from start to end allowed by a policy, without following
+/// each of them, or `None` if there are too many to count.
+pub fn count_paths(system: &CaveSystem, policy: &Policy) -> Option<u64> {
+    let Some(rules) = Rules::new(system, policy) else {
+        return Some(0);
+    };
+    match rules.start_state() {
+        Some(state) => rules.count_from(state),
+        None => Some(0),
+    }
+}
+
+/// List the paths from start to end allowed by a policy, as the caves along
+/// each of them.
+pub fn list_paths(system: &CaveSystem, policy: &Policy) -> Vec<Vec<CaveId>> {
+    let Some(rules) = Rules::new(system, policy) else {
+        return Vec::new();
+    };
+    match rules.start_state() {
+        Some(state) => rules.list_from(state),
+        None => Vec::new(),
+    }
+}
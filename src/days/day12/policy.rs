@@ -0,0 +1,61 @@
+/// Rules for how many times paths can visit each cave.
+use std::collections::HashMap;
+
+/// How many times a path can visit each cave.
+///
+/// By default small caves can be visited once and big caves any number of
+/// times, which each rule relaxes or restricts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Policy {
+    /// Most times any one small cave of a path's choosing can be visited,
+    /// other than start and end.
+    pub single_cave: usize,
+    /// Most visits to small caves after the first to each, in total, which
+    /// small caves without their own limit can be revisited up to.
+    pub total_revisits: Option<usize>,
+    /// Most times particular caves can be visited, big or small, instead of
+    /// their default, where caves with a limit of 0 are forbidden.
+    pub limits: HashMap<String, usize>,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            single_cave: 1,
+            total_revisits: None,
+            limits: HashMap::new(),
+        }
+    }
+}
+
+impl Policy {
+    /// Create the policy of part one, where small caves are visited once.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow any one small cave to be visited up to `times` times.
+    pub fn single_cave(mut self, times: usize) -> Self {
+        self.single_cave = times;
+        self
+    }
+
+    /// Allow at most `revisits` visits to small caves after the first to
+    /// each, in total, revisiting any small cave other than start and end
+    /// which doesn't have its own limit until then.
+    pub fn total_revisits(mut self, revisits: usize) -> Self {
+        self.total_revisits = Some(revisits);
+        self
+    }
+
+    /// Allow a cave to be visited at most `times` times.
+    pub fn limit(mut self, cave: &str, times: usize) -> Self {
+        self.limits.insert(cave.to_string(), times);
+        self
+    }
+
+    /// Never allow a cave to be visited.
+    pub fn forbid(self, cave: &str) -> Self {
+        self.limit(cave, 0)
+    }
+}
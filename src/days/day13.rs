@@ -0,0 +1,349 @@
+/// Solution to Advent of Code Challenge Day 13.
+use crate::grid::Grid;
+use crate::ocr;
+use crate::{try_parse_input_lines, try_parse_input_with, ParseError, Solution};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point(pub usize, pub usize);
+
+impl FromStr for Point {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed: Vec<usize> = try_parse_input_with(s, |s| s.split(','))?;
+        match parsed[..] {
+            [x, y] => Ok(Self(x, y)),
+            _ => Err(ParseError::new(
+                s,
+                "Point should be two numbers separated by ','",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fold {
+    /// Fold the paper along a vertical line where x is the value.
+    AlongX(usize),
+    /// Fold the paper along a horizontal line where y is the value.
+    AlongY(usize),
+}
+
+impl FromStr for Fold {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (axis, num) = s
+            .split_once('=')
+            .ok_or_else(|| ParseError::new(s, "Fold should be of the form 'fold along x=N'"))?;
+        let num = num
+            .parse()
+            .map_err(|e| ParseError::new(num, e).offset_by(s, axis.len() + 1))?;
+        match axis {
+            "fold along x" => Ok(Self::AlongX(num)),
+            "fold along y" => Ok(Self::AlongY(num)),
+            _ => Err(ParseError::new(axis, "Fold should be along x or y")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Manual {
+    dots: Vec<Point>,
+    folds: Vec<Fold>,
+}
+
+impl Manual {
+    /// Get the paper after each fold in turn.
+    pub fn papers(&self) -> impl Iterator<Item = Paper> + '_ {
+        self.folds
+            .iter()
+            .scan(Paper::new(&self.dots), |paper, fold| {
+                *paper = paper.fold(*fold);
+                Some(paper.clone())
+            })
+    }
+}
+
+/// Get where a coordinate ends up after folding the far side of a line over
+/// onto the near side, when the paper is `len` long before folding.
+///
+/// Coordinates are measured back from the line, so that when the far side is
+/// longer the near side moves along to make room for the part of it which
+/// overhangs.
+fn fold_coord(coord: usize, line: usize, len: usize) -> Option<usize> {
+    let folded_len = line.max(len.saturating_sub(line + 1));
+    let distance = coord.abs_diff(line);
+    (distance > 0).then(|| folded_len - distance)
+}
+
+/// Transparent paper with dots on it, storing only where the dots are so
+/// that the paper can be any size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paper {
+    width: usize,
+    height: usize,
+    dots: BTreeSet<Point>,
+}
+
+impl Paper {
+    /// Create paper which is just large enough to hold the dots.
+    pub fn new(dots: &[Point]) -> Self {
+        Self {
+            width: dots.iter().map(|p| p.0 + 1).max().unwrap_or(0),
+            height: dots.iter().map(|p| p.1 + 1).max().unwrap_or(0),
+            dots: dots.iter().copied().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the number of dots, where overlapping dots count once.
+    pub fn len(&self) -> usize {
+        self.dots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.dots.contains(&point)
+    }
+
+    /// Get the dots in order of their x then y coordinate.
+    pub fn dots(&self) -> impl Iterator<Item = Point> + '_ {
+        self.dots.iter().copied()
+    }
+
+    /// Get a grid of the paper, where the cells with dots are true.
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from_fn(self.width, self.height, |(x, y)| self.contains(Point(x, y)))
+    }
+
+    /// Fold the paper in half along a line, though the halves needn't be the
+    /// same size.
+    ///
+    /// Any dots on the line are lost, which the puzzle promises never
+    /// happens.
+    pub fn fold(&self, fold: Fold) -> Paper {
+        let (width, height, dots) = match fold {
+            Fold::AlongX(line) => (
+                line.max(self.width.saturating_sub(line + 1)),
+                self.height,
+                self.dots
+                    .iter()
+                    .filter_map(|p| Some(Point(fold_coord(p.0, line, self.width)?, p.1)))
+                    .collect(),
+            ),
+            Fold::AlongY(line) => (
+                self.width,
+                line.max(self.height.saturating_sub(line + 1)),
+                self.dots
+                    .iter()
+                    .filter_map(|p| Some(Point(p.0, fold_coord(p.1, line, self.height)?)))
+                    .collect(),
+            ),
+        };
+        Paper {
+            width,
+            height,
+            dots,
+        }
+    }
+}
+
+impl fmt::Display for Paper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| if self.contains(Point(x, y)) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+fn part_one(input: &Manual) -> u64 {
+    input.papers().next().map_or(0, |paper| paper.len() as u64)
+}
+
+fn folded_paper(input: &Manual) -> Paper {
+    input
+        .papers()
+        .last()
+        .unwrap_or_else(|| Paper::new(&input.dots))
+}
+
+/// Read the letters on the folded paper, or give the paper itself if they
+/// can't be read so that it can be read by eye instead.
+fn part_two(input: &Manual) -> String {
+    let paper = folded_paper(input);
+    ocr::read(&paper.to_grid()).unwrap_or_else(|_| paper.to_string())
+}
+
+fn get_manual(input: &str) -> Result<Manual, ParseError> {
+    let (dots, folds) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            input,
+            "Expected the dots and folds separated by a blank line",
+        )
+    })?;
+    Ok(Manual {
+        dots: try_parse_input_lines(dots)?,
+        folds: try_parse_input_lines(folds).map_err(|e| e.offset_by(input, dots.len() + 2))?,
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Manual;
+    type PartOne = u64;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_manual(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+
+    /// Draw the paper after every fold, or only the first few.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        let paper = match args {
+            [] => folded_paper(input),
+            [flag, value] if flag == "--folds" => {
+                let folds: usize = value
+                    .parse()
+                    .map_err(|_| format!("Invalid number of folds: {}", value))?;
+                match folds {
+                    0 => Paper::new(&input.dots),
+                    _ => input
+                        .papers()
+                        .nth(folds - 1)
+                        .ok_or_else(|| format!("There are only {} folds", input.folds.len()))?,
+                }
+            }
+            _ => return Err("Expected only --folds N for day 13".to_string()),
+        };
+        Ok(format!("{}\n", paper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5"
+            .to_string();
+
+        let expected_code: String = "#####
+#...#
+#...#
+#...#
+#####
+.....
+....."
+            .to_string();
+
+        let manual = get_manual(&input).unwrap();
+
+        // Check each gives the right answer.
+        assert_eq!(part_one(&manual), 17);
+        assert_eq!(part_two(&manual), expected_code);
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let drawing = "#..#.####...##.#..#...##.###...##....##.
+#..#.#.......#.#..#....#.#..#.#..#....#.
+####.###.....#.####....#.#..#.#.......#.
+#..#.#.......#.#..#....#.###..#.......#.
+#..#.#....#..#.#..#.#..#.#.#..#..#.#..#.
+#..#.####..##..#..#..##..#..#..##...##..";
+
+        // Mirror the letters below a fold so they read the right way after it.
+        let mut dots = String::new();
+        for (y, row) in drawing.lines().enumerate() {
+            for (x, _) in row.match_indices('#') {
+                dots.push_str(&format!("{},{}\n", x, 12 - y));
+            }
+        }
+        let manual = get_manual(&format!("{}\nfold along y=6\n", dots)).unwrap();
+        assert_eq!(part_two(&manual), "HEJHJRCJ");
+    }
+
+    #[test]
+    fn test_uneven_folds() {
+        // Folding at x=1 reflects the longer right hand side, which overhangs
+        // the left hand side by two.
+        let paper = Paper::new(&[Point(0, 0), Point(2, 1), Point(5, 2)]);
+        let folded = paper.fold(Fold::AlongX(1));
+        assert_eq!((folded.width(), folded.height()), (4, 3));
+        assert_eq!(folded.to_string(), "...#\n...#\n#...");
+
+        // Folding past the paper leaves it as it is, but larger.
+        let folded = paper.fold(Fold::AlongY(5));
+        assert_eq!((folded.width(), folded.height()), (6, 5));
+        assert_eq!(
+            folded.dots().collect::<Vec<_>>(),
+            paper.dots().collect::<Vec<_>>()
+        );
+
+        // Coordinates far beyond any fixed size of paper.
+        let paper = Paper::new(&[Point(0, 0), Point(1_000_000, 3)]);
+        let folded = paper.fold(Fold::AlongX(500_000)).fold(Fold::AlongY(2));
+        assert_eq!(
+            folded.dots().collect::<Vec<_>>(),
+            [Point(0, 0), Point(0, 1)]
+        );
+        assert_eq!(folded.len(), 2);
+    }
+
+    #[test]
+    fn test_papers() {
+        let manual = get_manual("0,0\n3,4\n\nfold along y=2\nfold along x=2\n").unwrap();
+        let papers: Vec<Paper> = manual.papers().collect();
+        assert_eq!(papers[0].to_string(), "#..#\n....");
+        assert_eq!(papers[1].to_string(), "##\n..");
+    }
+}
@@ -0,0 +1,257 @@
+/// Solution to Advent of Code Challenge Day 14.
+use crate::{try_parse_input_with, ParseError, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+mod polymer;
+mod validate;
+
+pub use polymer::{Count, Overflow, PolymerEngine};
+pub use validate::{validate, Diagnostics, Duplicate, Missing};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Element(pub char);
+
+impl FromStr for Element {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) if !ch.is_whitespace() => Ok(Self(ch)),
+            _ => Err(ParseError::new(
+                s,
+                "Element should be a single character other than whitespace",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pair(pub [Element; 2]);
+
+impl FromStr for Pair {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let elements: Vec<Element> =
+            try_parse_input_with(s, |s| s.split("").filter(|s| !s.is_empty()))?;
+        let elements = elements
+            .try_into()
+            .map_err(|_| ParseError::new(s, "Pair should be exactly two characters"))?;
+        Ok(Self(elements))
+    }
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.0[0], self.0[1])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Insertion {
+    pub between: Pair,
+    pub insert: Element,
+}
+
+impl FromStr for Insertion {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (between, insert) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::new(s, "Insertion should be of the form 'AB -> C'"))?;
+        Ok(Self {
+            between: between.parse()?,
+            insert: insert
+                .parse()
+                .map_err(|e: ParseError| e.offset_by(s, between.len() + 4))?,
+        })
+    }
+}
+
+impl fmt::Display for Insertion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.between, self.insert)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instructions {
+    start: Vec<Element>,
+    insertions: Vec<Insertion>,
+    /// Line of the input each insertion is on, starting from 1.
+    lines: Vec<usize>,
+}
+
+/// Find the difference between the counts of the most and least common
+/// elements after the given number of steps.
+fn calculate_element_difference(input: &Instructions, steps: u64) -> u64 {
+    let counts = PolymerEngine::new(&input.start, &input.insertions)
+        .element_counts::<u64>(steps)
+        .unwrap_or_else(|e| panic!("Failed to count elements: {}", e));
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
+
+fn part_one(input: &Instructions) -> u64 {
+    calculate_element_difference(input, 10)
+}
+
+fn part_two(input: &Instructions) -> u64 {
+    calculate_element_difference(input, 40)
+}
+
+/// Read the template from the first line which isn't blank and a rule from
+/// every line after it which isn't blank, carrying on past any lines which
+/// are malformed to find them all.
+fn read_instructions(input: &str) -> (Instructions, Vec<ParseError>) {
+    let mut instructions = Instructions {
+        start: Vec::new(),
+        insertions: Vec::new(),
+        lines: Vec::new(),
+    };
+    let mut malformed = Vec::new();
+
+    let mut offset = 0;
+    let mut template = None;
+    for (i, raw) in input.split_inclusive('\n').enumerate() {
+        let line = raw.trim_end();
+        let line_offset = offset;
+        offset += raw.len();
+        if line.is_empty() {
+            continue;
+        }
+
+        if template.is_none() {
+            template = Some(line);
+            match try_parse_input_with(line, |s| s.split("").filter(|s| !s.is_empty())) {
+                Ok(start) => instructions.start = start,
+                Err(e) => malformed.push(e.offset_by(input, line_offset)),
+            }
+            continue;
+        }
+
+        match line.parse() {
+            Ok(insertion) => {
+                instructions.insertions.push(insertion);
+                instructions.lines.push(i + 1);
+            }
+            Err(e) => malformed.push(e.offset_by(input, line_offset)),
+        }
+    }
+
+    if template.is_none() {
+        malformed.push(ParseError::new(input, "Expected a polymer template"));
+    }
+    (instructions, malformed)
+}
+
+fn get_instructions(input: &str) -> Result<Instructions, ParseError> {
+    let (instructions, malformed) = read_instructions(input);
+    match malformed.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(instructions),
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Instructions;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_instructions(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+
+    /// Report any problems with the rules, or which pairs can ever appear.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        match args {
+            [] => Ok(Diagnostics::new(input).to_string()),
+            [flag] if flag == "--reachable" => {
+                let engine = PolymerEngine::new(&input.start, &input.insertions);
+                Ok(engine
+                    .reachable_pairs()
+                    .into_iter()
+                    .map(|pair| {
+                        // The last rule for a pair is the one used.
+                        match input.insertions.iter().rev().find(|i| i.between == pair) {
+                            Some(insertion) => format!("{}\n", insertion),
+                            None => format!("{} (no rule)\n", pair),
+                        }
+                    })
+                    .collect())
+            }
+            _ => Err("Expected only --reachable for day 14".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C"
+            .to_string();
+
+        let inputs = get_instructions(&input).unwrap();
+
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs), 1588);
+        assert_eq!(part_two(&inputs), 2188189693529);
+
+        let engine = PolymerEngine::new(&inputs.start, &inputs.insertions);
+        let counts = engine.element_counts::<u128>(10).unwrap();
+        let expected = [('B', 1749), ('C', 298), ('H', 161), ('N', 865)];
+        assert_eq!(counts, expected.map(|(e, c)| (Element(e), c)).into());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!("A".parse::<Element>().is_ok());
+        assert!("AB".parse::<Element>().is_err());
+        assert!(" ".parse::<Element>().is_err());
+        assert!("ABC".parse::<Pair>().is_err());
+
+        // Any number of blank lines can separate the rules from the template.
+        let inputs = get_instructions("NN\n\n\nNN -> C\n\nNC -> N\n").unwrap();
+        assert_eq!(inputs.lines, [4, 6]);
+
+        let e = get_instructions("NN\n\nNN -> C\nNC -> NN\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 7, "NN"));
+    }
+}
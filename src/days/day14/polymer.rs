@@ -0,0 +1,327 @@
+/// Expanding polymers by inserting elements between pairs, by counting how
+/// many of each pair there are rather than building the polymer itself.
+use super::{Element, Insertion, Pair};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+/// A type of number to count elements with, where arithmetic is checked for
+/// overflow.
+pub trait Count: Copy + Ord + fmt::Debug + fmt::Display {
+    const ZERO: Self;
+    const ONE: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_count {
+    ($($t:ty),*) => {
+        $(
+            impl Count for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_count!(u64, u128);
+
+/// Error for counts which are too large for the type counting them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Counts are too large for the type counting them")
+    }
+}
+
+impl Error for Overflow {}
+
+fn add<C: Count>(a: C, b: C) -> Result<C, Overflow> {
+    a.checked_add(b).ok_or(Overflow)
+}
+
+fn mul<C: Count>(a: C, b: C) -> Result<C, Overflow> {
+    a.checked_mul(b).ok_or(Overflow)
+}
+
+/// Square matrix of counts, stored row by row.
+#[derive(Debug, Clone)]
+struct Matrix<C> {
+    size: usize,
+    cells: Vec<C>,
+}
+
+impl<C: Count> Matrix<C> {
+    fn zero(size: usize) -> Self {
+        Self {
+            size,
+            cells: vec![C::ZERO; size * size],
+        }
+    }
+
+    fn get(&self, row: usize, column: usize) -> C {
+        self.cells[row * self.size + column]
+    }
+
+    fn multiply(&self, other: &Matrix<C>) -> Result<Matrix<C>, Overflow> {
+        let mut product = Self::zero(self.size);
+        for row in 0..self.size {
+            for k in 0..self.size {
+                // Most pairs only become a few others, so skip the zeros.
+                let a = self.get(row, k);
+                if a == C::ZERO {
+                    continue;
+                }
+                for column in 0..self.size {
+                    let cell = &mut product.cells[row * self.size + column];
+                    *cell = add(*cell, mul(a, other.get(k, column))?)?;
+                }
+            }
+        }
+        Ok(product)
+    }
+
+    fn apply(&self, vector: &[C]) -> Result<Vec<C>, Overflow> {
+        (0..self.size)
+            .map(|row| {
+                (0..self.size).try_fold(C::ZERO, |sum, column| {
+                    add(sum, mul(self.get(row, column), vector[column])?)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Expands a polymer template by a set of insertion rules, where pairs
+/// without a rule are left as they are.
+///
+/// Each step maps the count of every pair linearly to the counts of pairs
+/// on the next step, so that the counts after any number of steps are found
+/// by raising that map to the power of the number of steps.
+#[derive(Debug, Clone)]
+pub struct PolymerEngine {
+    /// Every element in the template or the rules, where each pair of them
+    /// is indexed by the index of the first times the number of elements
+    /// plus the index of the second.
+    elements: Vec<Element>,
+    template: Vec<Element>,
+    /// The pairs each pair becomes after a step.
+    becomes: Vec<Vec<usize>>,
+}
+
+impl PolymerEngine {
+    pub fn new(template: &[Element], insertions: &[Insertion]) -> Self {
+        let mut elements: Vec<Element> = template
+            .iter()
+            .copied()
+            .chain(
+                insertions
+                    .iter()
+                    .flat_map(|i| [i.between.0[0], i.between.0[1], i.insert]),
+            )
+            .collect();
+        elements.sort_unstable();
+        elements.dedup();
+
+        let mut engine = Self {
+            becomes: (0..elements.len().pow(2)).map(|pair| vec![pair]).collect(),
+            elements,
+            template: template.to_vec(),
+        };
+        for insertion in insertions {
+            let Pair([first, second]) = insertion.between;
+            let pair = engine.pair_index(first, second);
+            engine.becomes[pair] = vec![
+                engine.pair_index(first, insertion.insert),
+                engine.pair_index(insertion.insert, second),
+            ];
+        }
+        engine
+    }
+
+    /// Every element in the template or the rules, in order.
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    fn element_index(&self, element: Element) -> usize {
+        self.elements.binary_search(&element).unwrap()
+    }
+
+    fn pair_index(&self, first: Element, second: Element) -> usize {
+        self.element_index(first) * self.elements.len() + self.element_index(second)
+    }
+
+    fn pair(&self, index: usize) -> Pair {
+        let n = self.elements.len();
+        Pair([self.elements[index / n], self.elements[index % n]])
+    }
+
+    /// Find the index of every pair which is in the polymer on any step, in
+    /// order.
+    fn reachable(&self) -> Vec<usize> {
+        let mut reached = vec![false; self.becomes.len()];
+        let mut to_visit: Vec<usize> = self
+            .template
+            .windows(2)
+            .map(|window| self.pair_index(window[0], window[1]))
+            .collect();
+        while let Some(pair) = to_visit.pop() {
+            if !reached[pair] {
+                reached[pair] = true;
+                to_visit.extend(&self.becomes[pair]);
+            }
+        }
+        (0..reached.len()).filter(|pair| reached[*pair]).collect()
+    }
+
+    /// Find every pair which is in the polymer on any step, in order.
+    pub fn reachable_pairs(&self) -> Vec<Pair> {
+        self.reachable()
+            .into_iter()
+            .map(|pair| self.pair(pair))
+            .collect()
+    }
+
+    /// Count each pair in the polymer after the given number of steps, giving
+    /// the index of each pair which is ever in the polymer with its count.
+    fn pair_counts<C: Count>(&self, steps: u64) -> Result<Vec<(usize, C)>, Overflow> {
+        // Only the pairs which are ever in the polymer are counted, as the
+        // counts of the others could overflow even when these don't.
+        let reachable = self.reachable();
+        let mut positions = vec![None; self.becomes.len()];
+        for (position, pair) in reachable.iter().enumerate() {
+            positions[*pair] = Some(position);
+        }
+        let position = |pair: usize| positions[pair].unwrap();
+
+        let size = reachable.len();
+        let mut counts = vec![C::ZERO; size];
+        for window in self.template.windows(2) {
+            let pair = position(self.pair_index(window[0], window[1]));
+            counts[pair] = add(counts[pair], C::ONE)?;
+        }
+
+        // The matrix for a single step, from each pair (column) to the pairs
+        // it becomes (rows).
+        let mut power = Matrix::zero(size);
+        for (column, pair) in reachable.iter().enumerate() {
+            for new_pair in &self.becomes[*pair] {
+                let cell = &mut power.cells[position(*new_pair) * size + column];
+                *cell = add(*cell, C::ONE)?;
+            }
+        }
+
+        // Apply the step matrix squared for each bit set in the number of
+        // steps, which all commute as they're powers of the same matrix.
+        let mut steps = steps;
+        while steps > 0 {
+            if steps & 1 == 1 {
+                counts = power.apply(&counts)?;
+            }
+            steps >>= 1;
+            if steps > 0 {
+                power = power.multiply(&power)?;
+            }
+        }
+        Ok(reachable.into_iter().zip(counts).collect())
+    }
+
+    /// Count each element in the polymer after the given number of steps.
+    pub fn element_counts<C: Count>(&self, steps: u64) -> Result<BTreeMap<Element, C>, Overflow> {
+        let pair_counts = self.pair_counts::<C>(steps)?;
+        let mut counts = BTreeMap::new();
+
+        // Every element is the first of a pair, apart from the last element,
+        // which never changes.
+        let last = self.template.last().map(|e| (*e, C::ONE));
+        let firsts = pair_counts
+            .into_iter()
+            .map(|(pair, count)| (self.elements[pair / self.elements.len()], count));
+        for (element, count) in firsts.chain(last) {
+            if count != C::ZERO {
+                let total = counts.entry(element).or_insert(C::ZERO);
+                *total = add(*total, count)?;
+            }
+        }
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{get_instructions, Instructions};
+    use super::*;
+
+    /// Expand the polymer itself for a number of steps.
+    fn expand(input: &Instructions, steps: usize) -> Vec<Element> {
+        let mut polymer = input.start.clone();
+        for _ in 0..steps {
+            let mut next = vec![polymer[0]];
+            for window in polymer.windows(2) {
+                let between = Pair([window[0], window[1]]);
+                if let Some(i) = input.insertions.iter().find(|i| i.between == between) {
+                    next.push(i.insert);
+                }
+                next.push(window[1]);
+            }
+            polymer = next;
+        }
+        polymer
+    }
+
+    #[test]
+    fn test_element_counts() {
+        // Rules over any characters, where some pairs have no rule.
+        let input = "a1Za
+
+a1 -> Z
+Za -> 1
+1Z -> a
+aa -> a";
+        let input = get_instructions(input).unwrap();
+        let engine = PolymerEngine::new(&input.start, &input.insertions);
+
+        for steps in 0..8 {
+            let mut expected = BTreeMap::new();
+            for element in expand(&input, steps) {
+                *expected.entry(element).or_insert(0) += 1;
+            }
+            assert_eq!(engine.element_counts::<u64>(steps as u64), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_large_steps() {
+        let input = get_instructions("NN\n\nNN -> N").unwrap();
+        let engine = PolymerEngine::new(&input.start, &input.insertions);
+
+        // The polymer doubles in length each step, less one.
+        let n = Element('N');
+        assert_eq!(
+            engine.element_counts::<u64>(63),
+            Ok(BTreeMap::from([(n, (1 << 63) + 1)]))
+        );
+        assert_eq!(engine.element_counts::<u64>(64), Err(Overflow));
+        assert_eq!(
+            engine.element_counts::<u128>(100),
+            Ok(BTreeMap::from([(n, (1 << 100) + 1)]))
+        );
+        assert_eq!(engine.element_counts::<u128>(1_000_000), Err(Overflow));
+
+        // Pairs which are never in the polymer don't overflow.
+        let input = get_instructions("AC\n\nXX -> X").unwrap();
+        let engine = PolymerEngine::new(&input.start, &input.insertions);
+        let expected = BTreeMap::from([(Element('A'), 1), (Element('C'), 1)]);
+        assert_eq!(engine.element_counts::<u64>(64), Ok(expected));
+    }
+}
@@ -0,0 +1,183 @@
+/// Checking a set of insertion rules for problems which parsing allows.
+use super::{read_instructions, Element, Instructions, Pair, PolymerEngine};
+use crate::ParseError;
+use std::collections::HashSet;
+use std::fmt;
+
+/// A pair given more than one rule, of which the last is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duplicate {
+    pub pair: Pair,
+    /// Line of each rule, starting from 1, along with the element it inserts.
+    pub rules: Vec<(usize, Element)>,
+}
+
+/// A pair of elements with no rule, which is left as it is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Missing {
+    pub pair: Pair,
+    /// Whether the pair is ever in the polymer.
+    pub reachable: bool,
+}
+
+/// Problems found with the template and rules.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diagnostics {
+    /// Every line which couldn't be parsed.
+    pub malformed: Vec<ParseError>,
+    pub duplicates: Vec<Duplicate>,
+    /// Pairs of the elements in the template or the rules without a rule.
+    pub missing: Vec<Missing>,
+}
+
+impl Diagnostics {
+    /// Find the problems with instructions which have been parsed.
+    pub fn new(instructions: &Instructions) -> Self {
+        let mut duplicates: Vec<Duplicate> = Vec::new();
+        for (i, insertion) in instructions.insertions.iter().enumerate() {
+            let rule = (instructions.lines[i], insertion.insert);
+            match duplicates.iter_mut().find(|d| d.pair == insertion.between) {
+                Some(duplicate) => duplicate.rules.push(rule),
+                None => duplicates.push(Duplicate {
+                    pair: insertion.between,
+                    rules: vec![rule],
+                }),
+            }
+        }
+        duplicates.retain(|d| d.rules.len() > 1);
+
+        let engine = PolymerEngine::new(&instructions.start, &instructions.insertions);
+        let reachable: HashSet<Pair> = engine.reachable_pairs().into_iter().collect();
+        let with_rules: HashSet<Pair> = instructions.insertions.iter().map(|i| i.between).collect();
+        let elements = engine.elements();
+        let missing = elements
+            .iter()
+            .flat_map(|first| elements.iter().map(|second| Pair([*first, *second])))
+            .filter(|pair| !with_rules.contains(pair))
+            .map(|pair| Missing {
+                pair,
+                reachable: reachable.contains(&pair),
+            })
+            .collect();
+
+        Self {
+            malformed: Vec::new(),
+            duplicates,
+            missing,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.malformed.is_empty() && self.duplicates.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Find every problem with an input, including each line which couldn't be
+/// parsed rather than only the first.
+pub fn validate(input: &str) -> Diagnostics {
+    let (instructions, malformed) = read_instructions(input);
+    Diagnostics {
+        malformed,
+        ..Diagnostics::new(&instructions)
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No problems found");
+        }
+        for e in &self.malformed {
+            writeln!(f, "Malformed {}", e)?;
+        }
+        for duplicate in &self.duplicates {
+            let rules: Vec<String> = duplicate
+                .rules
+                .iter()
+                .map(|(line, insert)| format!("{} on line {}", insert, line))
+                .collect();
+            writeln!(
+                f,
+                "Duplicate rules for {}: {}",
+                duplicate.pair,
+                rules.join(", ")
+            )?;
+        }
+        for missing in &self.missing {
+            let reachable = match missing.reachable {
+                true => "appears in the polymer",
+                false => "never appears",
+            };
+            writeln!(f, "No rule for {}, which {}", missing.pair, reachable)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let diagnostics = validate(
+            "ABC
+
+AB -> C
+AB -> A
+
+
+BC -> A
+BCD -> A
+CA -> 
+BA -> C",
+        );
+
+        let malformed: Vec<_> = diagnostics
+            .malformed
+            .iter()
+            .map(|e| (e.line, e.column, e.text.as_str()))
+            .collect();
+        assert_eq!(malformed, [(8, 1, "BCD"), (9, 1, "CA ->")]);
+
+        assert_eq!(
+            diagnostics.duplicates,
+            [Duplicate {
+                pair: Pair([Element('A'), Element('B')]),
+                rules: vec![(3, Element('C')), (4, Element('A'))],
+            }]
+        );
+
+        // AB gives AA, which stays, and BC gives AC, which stays, and BA, which
+        // gives CA.
+        let missing: Vec<_> = diagnostics
+            .missing
+            .iter()
+            .map(|m| (m.pair.to_string(), m.reachable))
+            .collect();
+        let expected = [
+            ("AA", true),
+            ("AC", true),
+            ("BB", false),
+            ("CA", true),
+            ("CB", false),
+            ("CC", false),
+        ];
+        assert_eq!(
+            missing,
+            expected.map(|(pair, reachable)| (pair.to_string(), reachable))
+        );
+    }
+
+    #[test]
+    fn test_valid_rules() {
+        assert!(validate("AB\n\nAB -> A\nAA -> B\nBA -> B\nBB -> A\n").is_empty());
+
+        let diagnostics = validate("\n\n");
+        assert_eq!(diagnostics.malformed.len(), 1);
+        assert_eq!(
+            diagnostics.malformed[0].reason,
+            "Expected a polymer template"
+        );
+    }
+}
@@ -0,0 +1,367 @@
+/// Solution to Advent of Code Challenge Day 15.
+use crate::grid::{neighbours4_within, Coord, Grid};
+use crate::pathfinding::{astar, Path};
+use crate::{ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::str::FromStr;
+
+type Risk = u8;
+type Cost = u64;
+
+/// A map of the risk at every position in the cave.
+pub trait RiskMap {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    /// Get the risk at a position, which must be within the map.
+    fn risk(&self, coord: Coord) -> Risk;
+}
+
+impl RiskMap for Grid<Risk> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn risk(&self, coord: Coord) -> Risk {
+        self[coord]
+    }
+}
+
+/// A view of a grid of risks repeated as tiles in each direction, where each
+/// tile has risks 1 higher than the tile above or to the left, wrapping back
+/// round to 1 after 9.
+///
+/// The risk at each position is calculated when needed, so the view takes no
+/// more memory however many tiles there are.
+#[derive(Debug, Clone, Copy)]
+pub struct TiledGrid<'a> {
+    grid: &'a Grid<Risk>,
+    tiles: usize,
+}
+
+impl<'a> TiledGrid<'a> {
+    pub fn new(grid: &'a Grid<Risk>, tiles: usize) -> Self {
+        Self { grid, tiles }
+    }
+}
+
+impl RiskMap for TiledGrid<'_> {
+    fn width(&self) -> usize {
+        self.grid.width() * self.tiles
+    }
+
+    fn height(&self) -> usize {
+        self.grid.height() * self.tiles
+    }
+
+    fn risk(&self, (x, y): Coord) -> Risk {
+        assert!(x < self.width() && y < self.height(), "Outside the tiles");
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let step = x / width + y / height;
+        let risk = self.grid[(x % width, y % height)] as usize;
+        ((risk + step - 1) % 9 + 1) as Risk
+    }
+}
+
+/// Find the path with the lowest total risk from the top left to the bottom
+/// right of the map, where the risk of the starting position doesn't count.
+///
+/// Panics if the map is empty.
+pub fn lowest_risk_path(map: &impl RiskMap) -> Path<Coord, Cost> {
+    let (width, height) = (map.width(), map.height());
+    let end = (width - 1, height - 1);
+    astar(
+        (0, 0),
+        |node| neighbours4_within(*node, width, height).map(|n| (n, map.risk(n) as Cost)),
+        // Every step has a risk of at least 1, so the distance never
+        // overestimates the remaining risk.
+        |(x, y)| ((end.0 - x) + (end.1 - y)) as Cost,
+        |node| *node == end,
+    )
+    .expect("The end should always be reachable")
+}
+
+fn get_risks(input: &str) -> Result<Grid<Risk>, ParseError> {
+    let grid: Grid<Risk> = input.parse()?;
+    if grid.width() == 0 {
+        return Err(ParseError::new(
+            input.trim_end(),
+            "Map should have at least one position",
+        ));
+    }
+
+    // Every step needs a risk of at least 1, for the tiles to wrap round from
+    // 9 and for the heuristic to never overestimate.
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if let Some(i) = line.find('0') {
+            let e = ParseError::new("0", "Risk should be from 1 to 9");
+            return Err(e.offset_by(input, offset + i));
+        }
+        offset += line.len();
+    }
+    Ok(grid)
+}
+
+fn part_one(input: &Grid<Risk>) -> u64 {
+    lowest_risk_path(input).cost
+}
+
+fn part_two(input: &Grid<Risk>) -> u64 {
+    lowest_risk_path(&TiledGrid::new(input, 5)).cost
+}
+
+/// Format to render the lowest risk path in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Render {
+    /// Digits coloured with ANSI escape codes, for the terminal.
+    Ansi,
+    /// A plain PPM image with a pixel for each position.
+    Ppm,
+    /// An SVG image with a square for each position.
+    Svg,
+}
+
+impl FromStr for Render {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Self::Ansi),
+            "ppm" => Ok(Self::Ppm),
+            "svg" => Ok(Self::Svg),
+            _ => Err(ParseError::new(s, "Format should be ansi, ppm or svg")),
+        }
+    }
+}
+
+/// Side length of the square for each position in SVG output.
+const SVG_CELL: usize = 10;
+
+/// Shade of grey for a risk, from white for the lowest down to dark grey for
+/// the highest.
+fn shade(risk: Risk) -> u8 {
+    255 - risk.saturating_sub(1) * 28
+}
+
+/// Render the risks of a map with a path through it highlighted.
+pub fn render_path(map: &impl RiskMap, path: &Path<Coord, Cost>, format: Render) -> String {
+    let on_path: HashSet<Coord> = path.nodes.iter().copied().collect();
+    let (width, height) = (map.width(), map.height());
+    let mut out = String::new();
+
+    match format {
+        Render::Ansi => {
+            for y in 0..height {
+                for x in 0..width {
+                    let risk = map.risk((x, y));
+                    if on_path.contains(&(x, y)) {
+                        write!(out, "\x1b[1;97;41m{}", risk).unwrap();
+                    } else {
+                        // Use the 256 colour greyscale ramp, which ends at
+                        // white on 255.
+                        let grey = 255 - risk.saturating_sub(1) as usize * 2;
+                        write!(out, "\x1b[0;38;5;{}m{}", grey, risk).unwrap();
+                    }
+                }
+                writeln!(out, "\x1b[0m").unwrap();
+            }
+            writeln!(out, "Total risk: {}", path.cost).unwrap();
+        }
+        Render::Ppm => {
+            writeln!(out, "P3\n{} {}\n255", width, height).unwrap();
+            for y in 0..height {
+                let row: Vec<String> = (0..width)
+                    .map(|x| {
+                        if on_path.contains(&(x, y)) {
+                            "255 0 0".to_string()
+                        } else {
+                            let grey = shade(map.risk((x, y)));
+                            format!("{} {} {}", grey, grey, grey)
+                        }
+                    })
+                    .collect();
+                writeln!(out, "{}", row.join(" ")).unwrap();
+            }
+        }
+        Render::Svg => {
+            let (svg_width, svg_height) = (width * SVG_CELL, height * SVG_CELL);
+            writeln!(
+                out,
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+                svg_width, svg_height, svg_width, svg_height
+            )
+            .unwrap();
+            for y in 0..height {
+                for x in 0..width {
+                    let grey = shade(map.risk((x, y)));
+                    writeln!(
+                        out,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"rgb({},{},{})\"/>",
+                        x * SVG_CELL,
+                        y * SVG_CELL,
+                        SVG_CELL,
+                        SVG_CELL,
+                        grey,
+                        grey,
+                        grey
+                    )
+                    .unwrap();
+                }
+            }
+            // Draw the path through the centre of each position along it.
+            let points: Vec<String> = path
+                .nodes
+                .iter()
+                .map(|(x, y)| {
+                    format!(
+                        "{},{}",
+                        x * SVG_CELL + SVG_CELL / 2,
+                        y * SVG_CELL + SVG_CELL / 2
+                    )
+                })
+                .collect();
+            writeln!(
+                out,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"red\" stroke-width=\"{}\"/>",
+                points.join(" "),
+                SVG_CELL / 3
+            )
+            .unwrap();
+            writeln!(out, "<title>Total risk: {}</title>", path.cost).unwrap();
+            writeln!(out, "</svg>").unwrap();
+        }
+    }
+
+    out
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Grid<Risk>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_risks(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+
+    /// Render the lowest risk path, through the map made of any number of
+    /// tiles of the input.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        let mut tiles = 1;
+        let mut format = Render::Ansi;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let value = args.next().ok_or(format!("Missing value for {}", arg))?;
+            match arg.as_str() {
+                "--tiles" => {
+                    tiles = value
+                        .parse()
+                        .ok()
+                        .filter(|tiles| *tiles > 0)
+                        .ok_or(format!("Invalid tile count: {}", value))?
+                }
+                "--format" => format = value.parse().map_err(|e: ParseError| e.reason)?,
+                _ => return Err(format!("Unexpected argument for day 15: {}", arg)),
+            }
+        }
+
+        let map = TiledGrid::new(input, tiles);
+        Ok(render_path(&map, &lowest_risk_path(&map), format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let input: String = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"
+            .to_string();
+
+        let inputs = Day15::parse(&input).unwrap();
+
+        // Check each gives the right answer.
+        assert_eq!(part_one(&inputs), 40);
+        assert_eq!(part_two(&inputs), 315);
+
+        let path = lowest_risk_path(&inputs);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&(9, 9)));
+        let risk: Cost = path.nodes[1..].iter().map(|n| inputs[*n] as Cost).sum();
+        assert_eq!(risk, 40);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        for input in ["", "\n"] {
+            let e = Day15::parse(input).unwrap_err();
+            assert_eq!((e.line, e.column), (1, 1));
+        }
+
+        let e = Day15::parse("11\n10").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "0"));
+    }
+
+    #[test]
+    fn test_render_path() {
+        let grid: Grid<Risk> = "19\n11".parse().unwrap();
+        let path = lowest_risk_path(&grid);
+        assert_eq!(path.nodes, vec![(0, 0), (0, 1), (1, 1)]);
+
+        let ansi = render_path(&grid, &path, Render::Ansi);
+        assert_eq!(ansi.matches("\x1b[1;97;41m").count(), 3);
+        assert!(ansi.ends_with("Total risk: 2\n"));
+
+        let ppm = render_path(&grid, &path, Render::Ppm);
+        assert_eq!(ppm, "P3\n2 2\n255\n255 0 0 31 31 31\n255 0 0 255 0 0\n");
+
+        let svg = render_path(&grid, &path, Render::Svg);
+        assert_eq!(svg.matches("<rect").count(), 4);
+        assert!(svg.contains("<polyline points=\"5,5 5,15 15,15\""));
+    }
+
+    #[test]
+    fn test_tiled_grid() {
+        let grid: Grid<Risk> = "8".parse().unwrap();
+        let tiled = TiledGrid::new(&grid, 5);
+        let rows: Vec<Vec<Risk>> = (0..5)
+            .map(|y| (0..5).map(|x| tiled.risk((x, y))).collect())
+            .collect();
+        assert_eq!(rows[0], vec![8, 9, 1, 2, 3]);
+        assert_eq!(rows[4], vec![3, 4, 5, 6, 7]);
+
+        // A single tile is the grid itself, and many tiles still fit.
+        let grid: Grid<Risk> = "19\n91".parse().unwrap();
+        assert_eq!(lowest_risk_path(&TiledGrid::new(&grid, 1)).cost, 10);
+        let tiled = TiledGrid::new(&grid, 50);
+        assert_eq!((tiled.width(), tiled.height()), (100, 100));
+        assert_eq!(tiled.risk((99, 99)), (1 + 98 - 1) % 9 + 1);
+    }
+}
@@ -0,0 +1,266 @@
+/// Assembling packets from the expressions they represent, e.g. to create
+/// transmissions to test the decoder with.
+///
+/// Expressions are written the same way packets are displayed, with each
+/// operator written as a function of its sub-packets:
+///
+/// ```text
+/// eq(sum(1, 3), product(2, 2))
+/// ```
+///
+/// Any packet can be followed by options in square brackets: `vN` for its
+/// version (0 by default) and, for operators, `len` or `count` for how to give
+/// the extent of its sub-packets (by count where possible by default):
+///
+/// ```text
+/// eq[len, v4](sum[v2](1[v2], 3[v4]), product[v6](2, 2[v2]))
+/// ```
+use super::codec::MAX_DEPTH;
+use super::{LengthMode, Operator, Packet};
+use crate::ParseError;
+
+/// Most sub-packets an operator can give the count of.
+const MAX_COUNT: usize = (1 << 11) - 1;
+
+/// Options given to a packet in square brackets.
+#[derive(Debug, Default)]
+struct Options {
+    version: u8,
+    length_mode: Option<LengthMode>,
+}
+
+/// Parses an expression, keeping track of the position reached in it.
+struct Assembler<'a> {
+    source: &'a str,
+    position: usize,
+    /// Number of operators the packet being read is nested within.
+    depth: usize,
+}
+
+impl<'a> Assembler<'a> {
+    /// Create an error for the text at the given position.
+    fn error(&self, start: usize, text: &str, reason: &str) -> ParseError {
+        ParseError::new(text, reason).offset_by(self.source, start)
+    }
+
+    /// Create an error for the next character, or the end of the expression.
+    fn unexpected(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some(ch) => {
+                let text = &self.source[self.position..self.position + ch.len_utf8()];
+                self.error(self.position, text, &format!("Expected {}", expected))
+            }
+            None => self.error(
+                self.position,
+                "",
+                &format!("Expected {} but the expression ended", expected),
+            ),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    /// Skip past a character if it's next, after any whitespace.
+    fn eat(&mut self, ch: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(ch) {
+            self.position += ch.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), ParseError> {
+        if self.eat(ch) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("{:?}", ch)))
+        }
+    }
+
+    /// Read a word of letters and digits, after any whitespace, giving its
+    /// position as well.
+    fn word(&mut self) -> (usize, &'a str) {
+        self.skip_whitespace();
+        let start = self.position;
+        let rest = &self.source[start..];
+        let len = rest
+            .find(|ch: char| !ch.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        self.position += len;
+        (start, &rest[..len])
+    }
+
+    /// Read the options in square brackets after a packet, if there are any.
+    fn options(&mut self) -> Result<Options, ParseError> {
+        let mut options = Options::default();
+        if !self.eat('[') {
+            return Ok(options);
+        }
+
+        loop {
+            match self.word() {
+                (_, "len") => options.length_mode = Some(LengthMode::Length),
+                (_, "count") => options.length_mode = Some(LengthMode::Count),
+                (start, option) => {
+                    options.version = option
+                        .strip_prefix('v')
+                        .and_then(|v| v.parse().ok())
+                        .filter(|v| *v < 8)
+                        .ok_or_else(|| {
+                            self.error(
+                                start,
+                                option,
+                                "Expected len, count or a version from v0 to v7",
+                            )
+                        })?
+                }
+            }
+            if !self.eat(',') {
+                break;
+            }
+        }
+        self.expect(']')?;
+        Ok(options)
+    }
+
+    /// Read a packet, along with all of its sub-packets.
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        let (start, word) = self.word();
+        if word.is_empty() {
+            return Err(self.unexpected("a literal or operator"));
+        }
+        // The same limit as decoding, so whatever decodes can be assembled.
+        if self.depth > MAX_DEPTH {
+            let reason = format!("Packet is nested within more than {} operators", MAX_DEPTH);
+            return Err(self.error(start, word, &reason));
+        }
+
+        if word.starts_with(|ch: char| ch.is_ascii_digit()) {
+            let literal = word
+                .parse()
+                .map_err(|_| self.error(start, word, "Expected a literal of up to 64 bits"))?;
+            let options = self.options()?;
+            if options.length_mode.is_some() {
+                return Err(self.error(start, word, "Literals don't have a length mode"));
+            }
+            return Ok(Packet::literal(options.version, literal));
+        }
+
+        let op =
+            Operator::from_name(word).ok_or_else(|| self.error(start, word, "Unknown operator"))?;
+        let options = self.options()?;
+
+        let mut packets = Vec::new();
+        self.expect('(')?;
+        if !self.eat(')') {
+            self.depth += 1;
+            loop {
+                packets.push(self.packet()?);
+                if !self.eat(',') {
+                    break;
+                }
+            }
+            self.depth -= 1;
+            self.expect(')')?;
+        }
+
+        let length_mode = options
+            .length_mode
+            .unwrap_or(if packets.len() <= MAX_COUNT {
+                LengthMode::Count
+            } else {
+                LengthMode::Length
+            });
+        Ok(Packet::operator(options.version, op, length_mode, packets))
+    }
+}
+
+/// Assemble the packet for an expression.
+pub fn assemble(source: &str) -> Result<Packet, ParseError> {
+    let mut assembler = Assembler {
+        source,
+        position: 0,
+        depth: 0,
+    };
+    let packet = assembler.packet()?;
+    assembler.skip_whitespace();
+    if assembler.peek().is_some() {
+        return Err(assembler.unexpected("the end of the expression"));
+    }
+    Ok(packet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{decode_hex, encode_hex, evaluate};
+    use super::*;
+
+    #[test]
+    fn test_assemble() {
+        let packet = assemble("sum(1, product(2, 7), max[len](3, 10), sum())").unwrap();
+        assert_eq!(evaluate(&packet), Ok(25));
+        assert_eq!(decode_hex(&encode_hex(&packet).unwrap()).unwrap(), packet);
+
+        // Options give every detail of a transmission.
+        let source = "eq[len, v4](sum[v2](1[v2], 3[v4]), product[v6](2, 2[v2]))";
+        let packet = assemble(source).unwrap();
+        assert_eq!(encode_hex(&packet).unwrap(), "9C0141080250320F1802104A08");
+
+        // Displayed packets can be assembled again.
+        for hex in [
+            "C200B40A82",
+            "04005AC33890",
+            "880086C3E88112",
+            "D8005AC2A8F0",
+        ] {
+            let packet = decode_hex(hex).unwrap();
+            let assembled = assemble(&packet.to_string()).unwrap();
+            assert_eq!(evaluate(&assembled), evaluate(&packet));
+        }
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let located = |source: &str| {
+            let e = assemble(source).unwrap_err();
+            (e.column, e.text)
+        };
+        assert_eq!(located("sum(1, x)"), (8, "x".to_string()));
+        assert_eq!(located("sum(1"), (6, String::new()));
+        assert_eq!(located("sum[v8](1)"), (5, "v8".to_string()));
+        assert_eq!(located("5[len]"), (1, "5".to_string()));
+        assert_eq!(
+            located("99999999999999999999"),
+            (1, "99999999999999999999".to_string())
+        );
+        assert_eq!(located("sum(1) 2"), (8, "2".to_string()));
+
+        let nested =
+            |depth, inner: &str| format!("{}{}{}", "sum(".repeat(depth), inner, ")".repeat(depth));
+        assert!(assemble(&nested(MAX_DEPTH, "1")).is_ok());
+        assert_eq!(
+            located(&nested(MAX_DEPTH + 1, "1")),
+            ((MAX_DEPTH + 1) * 4 + 1, "1".to_string())
+        );
+
+        // An operator without sub-packets can be as deep as a literal, both
+        // when assembling and decoding.
+        let packet = assemble(&nested(MAX_DEPTH, "sum()")).unwrap();
+        let decoded = decode_hex(&encode_hex(&packet).unwrap()).unwrap();
+        assert_eq!(decoded, packet);
+        assert_eq!(assemble(&decoded.to_string()).unwrap(), packet);
+        assert_eq!(
+            located(&nested(MAX_DEPTH + 1, "sum()")),
+            ((MAX_DEPTH + 1) * 4 + 1, "sum".to_string())
+        );
+    }
+}
@@ -0,0 +1,189 @@
+/// Reading and writing sequences of bits, most significant bit first.
+use super::codec::{DecodeError, DecodeErrorKind};
+
+/// Reads bits from a sequence of bytes, most significant bit first.
+///
+/// Positions are counted in bits from the start of the bytes, even when
+/// reading from a part of them.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    /// Position to stop reading at.
+    end: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a reader over every bit of the bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_len(bytes, bytes.len() * 8)
+    }
+
+    /// Create a reader over only the first `len` bits of the bytes.
+    pub fn with_len(bytes: &'a [u8], len: usize) -> Self {
+        assert!(len <= bytes.len() * 8, "Length is longer than the bytes");
+        Self {
+            bytes,
+            position: 0,
+            end: len,
+        }
+    }
+
+    /// Position of the next bit to read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of bits left to read.
+    pub fn remaining(&self) -> usize {
+        self.end - self.position
+    }
+
+    fn check_remaining(&self, bits: usize) -> Result<(), DecodeError> {
+        if bits > self.remaining() {
+            Err(DecodeError::new(
+                self.position,
+                DecodeErrorKind::UnexpectedEnd { needed: bits },
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read up to 64 bits as an unsigned number.
+    pub fn read(&mut self, bits: usize) -> Result<u64, DecodeError> {
+        assert!(bits <= 64, "Can only read up to 64 bits at once");
+        self.check_remaining(bits)?;
+
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes[self.position / 8];
+            let bit = (byte >> (7 - self.position % 8)) & 1;
+            value = value << 1 | bit as u64;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Split off a reader over the next `bits` bits, skipping this reader
+    /// past them.
+    pub fn take(&mut self, bits: usize) -> Result<BitReader<'a>, DecodeError> {
+        self.check_remaining(bits)?;
+        let taken = Self {
+            bytes: self.bytes,
+            position: self.position,
+            end: self.position + bits,
+        };
+        self.position += bits;
+        Ok(taken)
+    }
+}
+
+/// Writes bits to a sequence of bytes, most significant bit first.
+#[derive(Debug, Clone, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bits written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Write the lowest `bits` bits of a number.
+    pub fn write(&mut self, value: u64, bits: usize) {
+        assert!(bits <= 64, "Can only write up to 64 bits at once");
+        for i in (0..bits).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            let bit = ((value >> i) & 1) as u8;
+            *self.bytes.last_mut().unwrap() |= bit << (7 - self.len % 8);
+            self.len += 1;
+        }
+    }
+
+    /// Write every bit written to another writer.
+    pub fn append(&mut self, other: &BitWriter) {
+        let mut reader = BitReader::with_len(&other.bytes, other.len);
+        while reader.remaining() > 0 {
+            let bits = reader.remaining().min(64);
+            self.write(reader.read(bits).unwrap(), bits);
+        }
+    }
+
+    /// Get the bytes written, where the last byte is padded with zeros.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Convert upper case hexadecimal to bytes, giving the number of bits given
+/// as well since an odd number of digits leaves half of the last byte unused.
+pub fn hex_to_bytes(hex: &str) -> Result<(Vec<u8>, usize), DecodeError> {
+    let mut bytes = Vec::with_capacity(hex.len() / 2 + 1);
+    for (i, ch) in hex.chars().enumerate() {
+        let digit = match ch {
+            '0'..='9' | 'A'..='F' => ch.to_digit(16).unwrap() as u8,
+            _ => return Err(DecodeError::new(i * 4, DecodeErrorKind::InvalidHex(ch))),
+        };
+        if i % 2 == 0 {
+            bytes.push(digit << 4);
+        } else {
+            *bytes.last_mut().unwrap() |= digit;
+        }
+    }
+    let len = hex.chars().count() * 4;
+    Ok((bytes, len))
+}
+
+/// Convert bytes to upper case hexadecimal.
+pub fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_and_write() {
+        let (bytes, len) = hex_to_bytes("D2FE28").unwrap();
+        assert_eq!(len, 24);
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read(3), Ok(6));
+        assert_eq!(reader.read(3), Ok(4));
+        let mut taken = reader.take(5).unwrap();
+        assert_eq!(taken.read(5), Ok(0b10111));
+        assert_eq!(taken.read(1).unwrap_err().offset, 11);
+        assert_eq!(reader.position(), 11);
+        assert_eq!(reader.remaining(), 13);
+
+        let mut writer = BitWriter::new();
+        writer.write(6, 3);
+        writer.write(4, 3);
+        let mut rest = BitWriter::new();
+        rest.write(0b101111111000101, 15);
+        writer.append(&rest);
+        assert_eq!(writer.len(), 21);
+        assert_eq!(bytes_to_hex(&writer.into_bytes()), "D2FE28");
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(hex_to_bytes("A1F"), Ok((vec![0xA1, 0xF0], 12)));
+        let e = hex_to_bytes("A1g").unwrap_err();
+        assert_eq!(e.offset, 8);
+        assert_eq!(e.kind, DecodeErrorKind::InvalidHex('g'));
+    }
+}
@@ -0,0 +1,226 @@
+/// Decoding packets from, and encoding packets to, BITS transmissions.
+use super::bits::{bytes_to_hex, hex_to_bytes, BitReader, BitWriter};
+use super::{LengthMode, Operator, Packet, Payload};
+use std::error::Error;
+use std::fmt;
+
+/// Type ID of packets holding a literal value rather than sub-packets.
+const LITERAL_TYPE_ID: u8 = 4;
+/// Bits in the length of the sub-packets of an operator in length mode.
+const LENGTH_BITS: usize = 15;
+/// Bits in the number of sub-packets of an operator in count mode.
+const COUNT_BITS: usize = 11;
+/// Most operators a packet can be nested within, so that decoding a hostile
+/// transmission can't run out of stack.
+pub const MAX_DEPTH: usize = 256;
+
+/// Why a transmission couldn't be decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeErrorKind {
+    /// A character which isn't an upper case hexadecimal digit.
+    InvalidHex(char),
+    /// The transmission, or the sub-packets given by length, ended before a
+    /// value which needed more bits.
+    UnexpectedEnd { needed: usize },
+    /// A literal value which doesn't fit in 64 bits.
+    LiteralTooWide,
+    /// A packet nested within more than `MAX_DEPTH` operators.
+    TooDeep,
+}
+
+/// Error from decoding a transmission, giving the bit offset it happened at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub kind: DecodeErrorKind,
+}
+
+impl DecodeError {
+    pub fn new(offset: usize, kind: DecodeErrorKind) -> Self {
+        Self { offset, kind }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bit {}: ", self.offset)?;
+        match &self.kind {
+            DecodeErrorKind::InvalidHex(ch) => {
+                write!(f, "{:?} is not an upper case hexadecimal digit", ch)
+            }
+            DecodeErrorKind::UnexpectedEnd { needed } => {
+                write!(f, "Expected {} more bits but the packet ended", needed)
+            }
+            DecodeErrorKind::LiteralTooWide => write!(f, "Literal value is wider than 64 bits"),
+            DecodeErrorKind::TooDeep => {
+                write!(
+                    f,
+                    "Packet is nested within more than {} operators",
+                    MAX_DEPTH
+                )
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Error from encoding a packet which can't be represented in a transmission.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodeError {
+    /// An operator's sub-packets are too long to give their length in bits.
+    LengthTooLong(usize),
+    /// An operator has too many sub-packets to give their count.
+    TooManyPackets(usize),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::LengthTooLong(len) => write!(
+                f,
+                "Sub-packets are {} bits long, more than fits in {} bits",
+                len, LENGTH_BITS
+            ),
+            Self::TooManyPackets(count) => write!(
+                f,
+                "There are {} sub-packets, more than fits in {} bits",
+                count, COUNT_BITS
+            ),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+/// Decode a single packet, along with all of its sub-packets.
+pub fn decode(reader: &mut BitReader) -> Result<Packet, DecodeError> {
+    decode_within(reader, 0, &mut Vec::new())
+}
+
+/// Decode a single packet like `decode`, also giving the bit offset of every
+/// packet in the order they're read, where each operator comes before its
+/// sub-packets.
+pub fn decode_located(reader: &mut BitReader) -> Result<(Packet, Vec<usize>), DecodeError> {
+    let mut offsets = Vec::new();
+    let packet = decode_within(reader, 0, &mut offsets)?;
+    Ok((packet, offsets))
+}
+
+/// Decode a packet nested within `depth` operators, adding the offset of it
+/// and its sub-packets to `offsets`.
+fn decode_within(
+    reader: &mut BitReader,
+    depth: usize,
+    offsets: &mut Vec<usize>,
+) -> Result<Packet, DecodeError> {
+    if depth > MAX_DEPTH {
+        return Err(DecodeError::new(
+            reader.position(),
+            DecodeErrorKind::TooDeep,
+        ));
+    }
+    offsets.push(reader.position());
+    let version = reader.read(3)? as u8;
+    let type_id = reader.read(3)? as u8;
+
+    // Every type ID is an operator except for literals.
+    let Some(op) = Operator::from_type_id(type_id) else {
+        let start = reader.position();
+        let mut literal: u64 = 0;
+        loop {
+            let more = reader.read(1)? == 1;
+            let group = reader.read(4)?;
+            if literal >> 60 != 0 {
+                return Err(DecodeError::new(start, DecodeErrorKind::LiteralTooWide));
+            }
+            literal = literal << 4 | group;
+            if !more {
+                break;
+            }
+        }
+        return Ok(Packet::literal(version, literal));
+    };
+
+    let mut packets = Vec::new();
+    let length_mode = if reader.read(1)? == 0 {
+        let len = reader.read(LENGTH_BITS)? as usize;
+        let mut sub_reader = reader.take(len)?;
+        while sub_reader.remaining() > 0 {
+            packets.push(decode_within(&mut sub_reader, depth + 1, offsets)?);
+        }
+        LengthMode::Length
+    } else {
+        let count = reader.read(COUNT_BITS)?;
+        for _ in 0..count {
+            packets.push(decode_within(reader, depth + 1, offsets)?);
+        }
+        LengthMode::Count
+    };
+
+    Ok(Packet::operator(version, op, length_mode, packets))
+}
+
+/// Decode the outermost packet of a hexadecimal transmission, ignoring any
+/// padding after it.
+pub fn decode_hex(hex: &str) -> Result<Packet, DecodeError> {
+    let (bytes, len) = hex_to_bytes(hex)?;
+    decode(&mut BitReader::with_len(&bytes, len))
+}
+
+/// Encode a packet, along with all of its sub-packets.
+pub fn encode(packet: &Packet, writer: &mut BitWriter) -> Result<(), EncodeError> {
+    writer.write(packet.version as u64, 3);
+    let (op, length_mode, children) = match &packet.payload {
+        Payload::Literal(literal) => {
+            writer.write(LITERAL_TYPE_ID as u64, 3);
+            // Use as few groups of 4 bits as possible, but always at least one.
+            let groups = (64 - literal.leading_zeros() as usize).div_ceil(4);
+            for group in (0..groups.max(1)).rev() {
+                writer.write((group > 0) as u64, 1);
+                writer.write(literal >> (group * 4) & 0xF, 4);
+            }
+            return Ok(());
+        }
+        Payload::Operator {
+            op,
+            length_mode,
+            children,
+        } => (op, length_mode, children),
+    };
+
+    writer.write(op.type_id() as u64, 3);
+    match length_mode {
+        LengthMode::Length => {
+            let mut sub_writer = BitWriter::new();
+            for child in children {
+                encode(child, &mut sub_writer)?;
+            }
+            if sub_writer.len() >= 1 << LENGTH_BITS {
+                return Err(EncodeError::LengthTooLong(sub_writer.len()));
+            }
+            writer.write(0, 1);
+            writer.write(sub_writer.len() as u64, LENGTH_BITS);
+            writer.append(&sub_writer);
+        }
+        LengthMode::Count => {
+            if children.len() >= 1 << COUNT_BITS {
+                return Err(EncodeError::TooManyPackets(children.len()));
+            }
+            writer.write(1, 1);
+            writer.write(children.len() as u64, COUNT_BITS);
+            for child in children {
+                encode(child, writer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Encode a packet as a hexadecimal transmission, padded with zeros to a
+/// whole number of bytes.
+pub fn encode_hex(packet: &Packet) -> Result<String, EncodeError> {
+    let mut writer = BitWriter::new();
+    encode(packet, &mut writer)?;
+    Ok(bytes_to_hex(&writer.into_bytes()))
+}
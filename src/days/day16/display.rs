@@ -0,0 +1,133 @@
+/// Rendering packets as the expressions they represent.
+use super::{Packet, Payload};
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// How to render a packet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Render {
+    /// As an S-expression, e.g. `sum(3, product(2, 7))`.
+    Sexpr,
+    /// As an infix formula, e.g. `(3 + (2 * 7))`.
+    Infix,
+    /// As the value of every packet when evaluating it.
+    Trace,
+}
+
+impl FromStr for Render {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sexpr" => Ok(Self::Sexpr),
+            "infix" => Ok(Self::Infix),
+            "trace" => Ok(Self::Trace),
+            _ => Err(ParseError::new(s, "Format should be sexpr, infix or trace")),
+        }
+    }
+}
+
+/// Display a packet as an S-expression, where each operator is written as a
+/// function of its sub-packets.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.payload {
+            Payload::Literal(value) => write!(f, "{}", value),
+            Payload::Operator { op, children, .. } => {
+                write!(f, "{}(", op.name())?;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", child)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Render a packet as an infix formula, where operators with a symbol go
+/// between their bracketed sub-packets and the rest are written as functions.
+pub fn infix(packet: &Packet) -> String {
+    let (op, children) = match &packet.payload {
+        Payload::Literal(value) => return value.to_string(),
+        Payload::Operator { op, children, .. } => (op, children),
+    };
+
+    let operands: Vec<String> = children.iter().map(infix).collect();
+    match op.symbol() {
+        Some(symbol) if !operands.is_empty() => {
+            format!("({})", operands.join(&format!(" {} ", symbol)))
+        }
+        _ => format!("{}({})", op.name(), operands.join(", ")),
+    }
+}
+
+/// Evaluate a packet, recording a line for each packet giving its value,
+/// indented under the operator it belongs to.
+///
+/// An operator which can't be evaluated gives the error on its line instead,
+/// and every operator containing it has no value.
+fn trace_into(packet: &Packet, depth: usize, lines: &mut Vec<String>) -> Option<u64> {
+    let indent = "  ".repeat(depth);
+    let (op, children) = match &packet.payload {
+        Payload::Literal(value) => {
+            lines.push(format!("{}{}", indent, value));
+            return Some(*value);
+        }
+        Payload::Operator { op, children, .. } => (op, children),
+    };
+
+    // Leave a line for the operator, to fill in once its value is known.
+    let line = lines.len();
+    lines.push(String::new());
+    let values: Vec<Option<u64>> = children
+        .iter()
+        .map(|child| trace_into(child, depth + 1, lines))
+        .collect();
+
+    let values: Option<Vec<u64>> = values.into_iter().collect();
+    let (value, result) = match values.map(|values| op.apply(&values)) {
+        Some(Ok(value)) => (Some(value), value.to_string()),
+        Some(Err(e)) => (None, format!("error: {}", e)),
+        None => (None, "?".to_string()),
+    };
+    lines[line] = format!("{}{} = {}", indent, op.name(), result);
+    value
+}
+
+/// Render how a packet is evaluated, giving the value of every packet within
+/// it.
+pub fn trace(packet: &Packet) -> String {
+    let mut lines = Vec::new();
+    trace_into(packet, 0, &mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{assemble, decode_hex};
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let packet = decode_hex("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(packet.to_string(), "eq(sum(1, 3), product(2, 2))");
+        assert_eq!(infix(&packet), "((1 + 3) == (2 * 2))");
+        assert_eq!(
+            trace(&packet),
+            "eq = 1\n  sum = 4\n    1\n    3\n  product = 4\n    2\n    2\n"
+        );
+
+        let packet = decode_hex("880086C3E88112").unwrap();
+        assert_eq!(packet.to_string(), "min(7, 8, 9)");
+        assert_eq!(infix(&packet), "min(7, 8, 9)");
+
+        let packet = assemble("sum(1, gt(1, 2, 3))").unwrap();
+        assert_eq!(
+            trace(&packet),
+            "sum = ?\n  1\n  gt = error: gt needs exactly 2 sub-packets but has 3\n    1\n    2\n    3\n"
+        );
+    }
+}
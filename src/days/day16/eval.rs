@@ -0,0 +1,199 @@
+/// Evaluating the expressions packets represent.
+use super::{Packet, Payload};
+use std::error::Error;
+use std::fmt;
+
+/// Operation an operator packet applies to the values of its sub-packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+const OPERATORS: [Operator; 7] = [
+    Operator::Sum,
+    Operator::Product,
+    Operator::Minimum,
+    Operator::Maximum,
+    Operator::GreaterThan,
+    Operator::LessThan,
+    Operator::EqualTo,
+];
+
+impl Operator {
+    /// Get the operator for a packet type ID, if it isn't for a literal.
+    pub fn from_type_id(type_id: u8) -> Option<Self> {
+        OPERATORS.into_iter().find(|op| op.type_id() == type_id)
+    }
+
+    /// Type ID of packets applying this operator.
+    pub fn type_id(self) -> u8 {
+        match self {
+            Self::Sum => 0,
+            Self::Product => 1,
+            Self::Minimum => 2,
+            Self::Maximum => 3,
+            Self::GreaterThan => 5,
+            Self::LessThan => 6,
+            Self::EqualTo => 7,
+        }
+    }
+
+    /// Get the operator with a name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        OPERATORS.into_iter().find(|op| op.name() == name)
+    }
+
+    /// Name of the operator when written as a function.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sum => "sum",
+            Self::Product => "product",
+            Self::Minimum => "min",
+            Self::Maximum => "max",
+            Self::GreaterThan => "gt",
+            Self::LessThan => "lt",
+            Self::EqualTo => "eq",
+        }
+    }
+
+    /// Symbol of the operator when written between its operands, if it has
+    /// one.
+    pub fn symbol(self) -> Option<&'static str> {
+        match self {
+            Self::Sum => Some("+"),
+            Self::Product => Some("*"),
+            Self::Minimum | Self::Maximum => None,
+            Self::GreaterThan => Some(">"),
+            Self::LessThan => Some("<"),
+            Self::EqualTo => Some("=="),
+        }
+    }
+
+    /// Apply the operator to the values of the sub-packets.
+    pub fn apply(self, values: &[u64]) -> Result<u64, EvalError> {
+        let overflow = || EvalError::Overflow(self);
+        let empty = || EvalError::Empty(self);
+        match self {
+            Self::Sum => values
+                .iter()
+                .try_fold(0_u64, |sum, v| sum.checked_add(*v))
+                .ok_or_else(overflow),
+            Self::Product => values
+                .iter()
+                .try_fold(1_u64, |product, v| product.checked_mul(*v))
+                .ok_or_else(overflow),
+            Self::Minimum => values.iter().copied().min().ok_or_else(empty),
+            Self::Maximum => values.iter().copied().max().ok_or_else(empty),
+            Self::GreaterThan | Self::LessThan | Self::EqualTo => match values {
+                [a, b] => Ok(match self {
+                    Self::GreaterThan => a > b,
+                    Self::LessThan => a < b,
+                    _ => a == b,
+                } as u64),
+                _ => Err(EvalError::Arity {
+                    op: self,
+                    count: values.len(),
+                }),
+            },
+        }
+    }
+}
+
+/// Error from evaluating a packet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// The value of an operator doesn't fit in 64 bits.
+    Overflow(Operator),
+    /// A comparison doesn't have exactly two sub-packets.
+    Arity { op: Operator, count: usize },
+    /// A minimum or maximum has no sub-packets.
+    Empty(Operator),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow(op) => write!(f, "The value of {} overflows 64 bits", op.name()),
+            Self::Arity { op, count } => write!(
+                f,
+                "{} needs exactly 2 sub-packets but has {}",
+                op.name(),
+                count
+            ),
+            Self::Empty(op) => write!(f, "{} needs at least 1 sub-packet", op.name()),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+/// Evaluate the expression a packet represents.
+pub fn evaluate(packet: &Packet) -> Result<u64, EvalError> {
+    evaluate_indexed(packet).map_err(|(_, e)| e)
+}
+
+/// Evaluate a packet like `evaluate`, giving any error along with the index
+/// of the packet it happened in, counting packets in the order they're
+/// decoded.
+pub(super) fn evaluate_indexed(packet: &Packet) -> Result<u64, (usize, EvalError)> {
+    evaluate_from(packet, &mut 0)
+}
+
+/// Evaluate a packet with the given index, counting on `next` past it and
+/// its sub-packets.
+fn evaluate_from(packet: &Packet, next: &mut usize) -> Result<u64, (usize, EvalError)> {
+    let index = *next;
+    *next += 1;
+    match &packet.payload {
+        Payload::Literal(value) => Ok(*value),
+        Payload::Operator { op, children, .. } => {
+            let values = children
+                .iter()
+                .map(|child| evaluate_from(child, next))
+                .collect::<Result<Vec<_>, _>>()?;
+            op.apply(&values).map_err(|e| (index, e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator() {
+        for op in OPERATORS {
+            assert_eq!(Operator::from_type_id(op.type_id()), Some(op));
+            assert_eq!(Operator::from_name(op.name()), Some(op));
+        }
+        assert_eq!(Operator::from_type_id(4), None);
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        use Operator::*;
+        assert_eq!(Sum.apply(&[]), Ok(0));
+        assert_eq!(Product.apply(&[]), Ok(1));
+        assert_eq!(Sum.apply(&[u64::MAX, 1]), Err(EvalError::Overflow(Sum)));
+        assert_eq!(
+            Product.apply(&[1 << 32, 1 << 32]),
+            Err(EvalError::Overflow(Product))
+        );
+        assert_eq!(Minimum.apply(&[]), Err(EvalError::Empty(Minimum)));
+        assert_eq!(Maximum.apply(&[3, 9, 4]), Ok(9));
+        assert_eq!(LessThan.apply(&[3, 9]), Ok(1));
+        assert_eq!(
+            EqualTo.apply(&[3, 3, 3]),
+            Err(EvalError::Arity {
+                op: EqualTo,
+                count: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,323 @@
+/// Solution to Advent of Code Challenge Day 16.
+use crate::{ParseError, Solution};
+
+mod assembler;
+mod bits;
+mod codec;
+mod display;
+mod eval;
+
+pub use assembler::assemble;
+pub use bits::{BitReader, BitWriter};
+pub use codec::{
+    decode, decode_hex, decode_located, encode, encode_hex, DecodeError, DecodeErrorKind,
+    EncodeError, MAX_DEPTH,
+};
+pub use display::{infix, trace, Render};
+pub use eval::{evaluate, EvalError, Operator};
+
+/// How an operator gives the extent of its sub-packets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthMode {
+    /// The total length of the sub-packets in bits.
+    Length,
+    /// The number of sub-packets.
+    Count,
+}
+
+/// What a packet holds after its header.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// A literal value.
+    Literal(u64),
+    /// An operator applied to the values of sub-packets.
+    Operator {
+        op: Operator,
+        length_mode: LengthMode,
+        children: Vec<Packet>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packet {
+    /// Version, which fits in 3 bits.
+    pub version: u8,
+    pub payload: Payload,
+}
+
+impl Packet {
+    /// Create a packet holding a literal value.
+    ///
+    /// Panics if the version doesn't fit in 3 bits.
+    pub fn literal(version: u8, value: u64) -> Self {
+        assert!(version < 8, "Version should fit in 3 bits");
+        Self {
+            version,
+            payload: Payload::Literal(value),
+        }
+    }
+
+    /// Create an operator packet over some sub-packets.
+    ///
+    /// Panics if the version doesn't fit in 3 bits.
+    pub fn operator(
+        version: u8,
+        op: Operator,
+        length_mode: LengthMode,
+        children: Vec<Packet>,
+    ) -> Self {
+        assert!(version < 8, "Version should fit in 3 bits");
+        Self {
+            version,
+            payload: Payload::Operator {
+                op,
+                length_mode,
+                children,
+            },
+        }
+    }
+
+    /// Get the sub-packets, of which literals have none.
+    pub fn children(&self) -> &[Packet] {
+        match &self.payload {
+            Payload::Literal(_) => &[],
+            Payload::Operator { children, .. } => children,
+        }
+    }
+}
+
+/// Decode the outermost packet of a hexadecimal transmission and check it
+/// can be evaluated, locating any error at the hexadecimal digit holding the
+/// bit it happened at.
+fn packet_from(input: &str) -> Result<Packet, ParseError> {
+    let input = input.trim_end();
+    let error = |offset: usize, reason: String| {
+        let digit = (offset / 4).min(input.len().saturating_sub(1));
+        let text = input.get(digit..digit + 1).unwrap_or_default();
+        ParseError::new(text, reason).offset_by(input, digit)
+    };
+
+    let (packet, offsets) = bits::hex_to_bytes(input)
+        .and_then(|(bytes, len)| decode_located(&mut BitReader::with_len(&bytes, len)))
+        .map_err(|e| error(e.offset, e.to_string()))?;
+    if let Err((index, e)) = eval::evaluate_indexed(&packet) {
+        let offset = offsets[index];
+        return Err(error(offset, format!("bit {}: {}", offset, e)));
+    }
+    Ok(packet)
+}
+
+fn sum_version(input: &Packet) -> u64 {
+    input.version as u64 + input.children().iter().map(sum_version).sum::<u64>()
+}
+
+fn part_one(input: &Packet) -> u64 {
+    sum_version(input)
+}
+
+fn part_two(input: &Packet) -> u64 {
+    evaluate(input).expect("Parsing checks the packet can be evaluated")
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        packet_from(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+
+    /// Show the expression the packet represents, or how it's evaluated.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        let render = match args {
+            [] => Render::Sexpr,
+            [flag, value] if flag == "--format" => {
+                value.parse().map_err(|e: ParseError| e.reason)?
+            }
+            _ => return Err("Expected only --format sexpr|infix|trace for day 16".to_string()),
+        };
+
+        Ok(match render {
+            Render::Sexpr => format!("{}\n", input),
+            Render::Infix => format!("{}\n", infix(input)),
+            Render::Trace => trace(input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_example() {
+        let test1 = packet_from("D2FE28").unwrap();
+        let test2 = packet_from("38006F45291200").unwrap();
+        let test3 = packet_from("EE00D40C823060").unwrap();
+        let test4 = packet_from("8A004A801A8002F478").unwrap();
+        let test5 = packet_from("620080001611562C8802118E34").unwrap();
+        let test6 = packet_from("C0015000016115A2E0802F182340").unwrap();
+        let test7 = packet_from("A0016C880162017C3686B18A3D4780").unwrap();
+
+        // Check each gives the right answer.
+        assert_eq!(part_one(&test1), 6);
+        assert_eq!(part_one(&test2), 9);
+        assert_eq!(part_one(&test3), 14);
+        assert_eq!(part_one(&test4), 16);
+        assert_eq!(part_one(&test5), 12);
+        assert_eq!(part_one(&test6), 23);
+        assert_eq!(part_one(&test7), 31);
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let test1 = packet_from("C200B40A82").unwrap();
+        let test2 = packet_from("04005AC33890").unwrap();
+        let test3 = packet_from("880086C3E88112").unwrap();
+        let test4 = packet_from("CE00C43D881120").unwrap();
+        let test5 = packet_from("D8005AC2A8F0").unwrap();
+        let test6 = packet_from("F600BC2D8F").unwrap();
+        let test7 = packet_from("9C005AC2F8F0").unwrap();
+        let test8 = packet_from("9C0141080250320F1802104A08").unwrap();
+
+        // Check each gives the right answer.
+        assert_eq!(part_two(&test1), 3);
+        assert_eq!(part_two(&test2), 54);
+        assert_eq!(part_two(&test3), 7);
+        assert_eq!(part_two(&test4), 9);
+        assert_eq!(part_two(&test5), 1);
+        assert_eq!(part_two(&test6), 0);
+        assert_eq!(part_two(&test7), 0);
+        assert_eq!(part_two(&test8), 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let examples = [
+            "D2FE28",
+            "38006F45291200",
+            "EE00D40C823060",
+            "8A004A801A8002F478",
+            "620080001611562C8802118E34",
+            "C0015000016115A2E0802F182340",
+            "A0016C880162017C3686B18A3D4780",
+            "9C0141080250320F1802104A08",
+        ];
+        for hex in examples {
+            let packet = decode_hex(hex).unwrap();
+            let encoded = encode_hex(&packet).unwrap();
+            assert_eq!(decode_hex(&encoded).unwrap(), packet);
+            assert_eq!(encoded, hex);
+        }
+
+        let packet = Packet::operator(
+            7,
+            Operator::Maximum,
+            LengthMode::Length,
+            vec![
+                Packet::literal(1, 0),
+                Packet::literal(2, u64::MAX),
+                Packet::operator(0, Operator::Sum, LengthMode::Count, vec![]),
+            ],
+        );
+        assert_eq!(decode_hex(&encode_hex(&packet).unwrap()).unwrap(), packet);
+
+        let too_many = Packet::operator(
+            0,
+            Operator::Sum,
+            LengthMode::Count,
+            vec![Packet::literal(0, 0); 2048],
+        );
+        assert_eq!(
+            encode_hex(&too_many),
+            Err(EncodeError::TooManyPackets(2048))
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // Truncated before the last group of a literal.
+        let e = decode_hex("D2FE").unwrap_err();
+        assert_eq!(
+            (e.offset, e.kind),
+            (16, DecodeErrorKind::UnexpectedEnd { needed: 1 })
+        );
+
+        // The first sub-packet runs over the length of 3 bits given by its
+        // operator, which starts after the 22 bit header.
+        let e = decode_hex("38000F45291200").unwrap_err();
+        assert_eq!(
+            (e.offset, e.kind),
+            (25, DecodeErrorKind::UnexpectedEnd { needed: 3 })
+        );
+
+        // 17 groups of 4 bits is too wide for a literal.
+        let mut writer = BitWriter::new();
+        writer.write(4, 6);
+        for _ in 0..16 {
+            writer.write(0b11111, 5);
+        }
+        writer.write(0b01111, 5);
+        let hex = bits::bytes_to_hex(&writer.into_bytes());
+        let e = decode_hex(&hex).unwrap_err();
+        assert_eq!((e.offset, e.kind), (6, DecodeErrorKind::LiteralTooWide));
+
+        // Operators nested too deeply, each with a single sub-packet.
+        let nested = |depth: usize| {
+            let mut writer = BitWriter::new();
+            for _ in 0..depth {
+                // Version 0, a sum, in count mode with one sub-packet.
+                writer.write(0, 3);
+                writer.write(0, 3);
+                writer.write(1, 1);
+                writer.write(1, 11);
+            }
+            // Version 0, a literal of 1.
+            writer.write(0, 3);
+            writer.write(4, 3);
+            writer.write(0b00001, 5);
+            bits::bytes_to_hex(&writer.into_bytes())
+        };
+        assert_eq!(sum_version(&decode_hex(&nested(MAX_DEPTH)).unwrap()), 0);
+        let e = decode_hex(&nested(50_000)).unwrap_err();
+        assert_eq!(
+            (e.offset, e.kind),
+            ((MAX_DEPTH + 1) * 18, DecodeErrorKind::TooDeep)
+        );
+
+        let e = packet_from("D2FE2G\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 6, "G"));
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        let located = |source: &str| {
+            let hex = encode_hex(&assemble(source).unwrap()).unwrap();
+            let e = packet_from(&hex).unwrap_err();
+            (e.column, e.reason)
+        };
+
+        // Each second operand starts after its operator's 18 bit header and
+        // the 11 bit literal, in the 8th digit.
+        let (column, reason) = located("sum(1, gt(1))");
+        assert_eq!(column, 8);
+        assert_eq!(reason, "bit 29: gt needs exactly 2 sub-packets but has 1");
+        let (column, reason) = located("min(3, product(4294967296, 4294967296))");
+        assert_eq!(column, 8);
+        assert_eq!(reason, "bit 29: The value of product overflows 64 bits");
+        assert_eq!(located("max()").0, 1);
+    }
+}
@@ -0,0 +1,609 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Solution to Advent of Code Challenge Day 17.
+use crate::{try_parse_input_with, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct TargetArea {
+    xrange: RangeInclusive<isize>,
+    yrange: RangeInclusive<isize>,
+}
+
+impl FromStr for TargetArea {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const FORMAT: &str = "Target area should be of the form 'target area: x=A..B, y=C..D'";
+
+        let prefix = "target area: ";
+        let ranges = s
+            .trim_end()
+            .strip_prefix(prefix)
+            .ok_or_else(|| ParseError::new(s, FORMAT))?;
+        let (xs, ys) = ranges
+            .split_once(", ")
+            .ok_or_else(|| ParseError::new(ranges, FORMAT).offset_by(s, prefix.len()))?;
+
+        Ok(Self {
+            xrange: parse_range(xs, "x=").map_err(|e| e.offset_by(s, prefix.len()))?,
+            yrange: parse_range(ys, "y=")
+                .map_err(|e| e.offset_by(s, prefix.len() + xs.len() + 2))?,
+        })
+    }
+}
+
+impl TargetArea {
+    /// Check that finitely many initial velocities hit the target.
+    pub fn check_bounded(&self) -> Result<(), Unbounded> {
+        // A probe launched at dx stops after moving dx(dx + 1) / 2 either way.
+        let reach = self.xrange.start().abs().max(self.xrange.end().abs());
+        let stops_over = (0..)
+            .map(|dx| position(dx, dx))
+            .take_while(|x| *x <= reach)
+            .any(|x| self.xrange.contains(&x) || self.xrange.contains(&-x));
+        if self.yrange.contains(&0) && stops_over {
+            Err(Unbounded)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Parse a range of the form e.g. "x=20..30" for the given axis prefix.
+fn parse_range(s: &str, prefix: &str) -> Result<RangeInclusive<isize>, ParseError> {
+    let range = s
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("Range should start with '{}'", prefix)))?;
+    let bounds: Vec<isize> =
+        try_parse_input_with(range, |s| s.split("..")).map_err(|e| e.offset_by(s, prefix.len()))?;
+    match bounds[..] {
+        [start, end] if start <= end => Ok(start..=end),
+        [_, _] => Err(
+            ParseError::new(range, "Range should go from its lowest to highest")
+                .offset_by(s, prefix.len()),
+        ),
+        _ => Err(ParseError::new(s, "Range should be of the form 'A..B'")),
+    }
+}
+
+/// Initial velocity of the probe, as (dx, dy).
+pub type Velocity = (isize, isize);
+
+/// Range of steps after launch, from 1, for which the probe is within the
+/// target along one axis, where there's no last step if it stays there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StepWindow {
+    first: isize,
+    last: Option<isize>,
+}
+
+impl StepWindow {
+    fn overlaps(&self, other: &StepWindow) -> bool {
+        let first = self.first.max(other.first);
+        match (self.last, other.last) {
+            (Some(a), Some(b)) => first <= a.min(b),
+            (Some(last), None) | (None, Some(last)) => first <= last,
+            (None, None) => true,
+        }
+    }
+}
+
+/// Position along an axis after `n` steps, while the velocity started at `v`
+/// is still being reduced by 1 each step.
+fn position(v: isize, n: isize) -> isize {
+    n * v - n * (n - 1) / 2
+}
+
+/// The roots of `n^2 - (2v + 1)n + 2p = 0`, which are the (real) steps at
+/// which the position is `p` for a velocity started at `v`.
+fn steps_at(v: isize, p: isize) -> Option<(f64, f64)> {
+    let b = (2 * v + 1) as f64;
+    let discriminant = b * b - 8.0 * p as f64;
+    (discriminant >= 0.0).then(|| {
+        let root = discriminant.sqrt();
+        ((b - root) / 2.0, (b + root) / 2.0)
+    })
+}
+
+/// Find the first step in `from..=to` at which a condition holds, or `to` if
+/// it never does, where once the condition holds it holds for every later
+/// step.
+///
+/// Starts from an estimate, so only floating point error needs correcting,
+/// where without one the condition holds on every step or none of them.
+fn first_step(
+    estimate: Option<f64>,
+    from: isize,
+    to: isize,
+    holds: impl Fn(isize) -> bool,
+) -> isize {
+    let mut n = match estimate {
+        Some(estimate) => (estimate.ceil() as isize).clamp(from, to),
+        None if holds(from) => from,
+        None => to,
+    };
+    while n > from && holds(n - 1) {
+        n -= 1;
+    }
+    while n < to && !holds(n) {
+        n += 1;
+    }
+    n
+}
+
+/// Get the windows of steps in `from..=to` where the position is within
+/// `min..=max`, while the position is strictly monotonic over those steps.
+fn monotonic_window(
+    v: isize,
+    from: isize,
+    to: isize,
+    (min, max): (isize, isize),
+    rising: bool,
+) -> Option<StepWindow> {
+    if from > to {
+        return None;
+    }
+
+    // The position enters the bounds through the bound it's heading towards
+    // and leaves past the other, estimating the steps at which it's at each
+    // bound from the smaller root for the rising steps, and the larger for
+    // the falling steps.
+    let sign = if rising { 1 } else { -1 };
+    let ahead = |n: isize| sign * position(v, n);
+    let (enter, leave) = if rising { (min, max) } else { (max, min) };
+    let estimate =
+        |bound: isize| steps_at(v, bound).map(|roots| if rising { roots.0 } else { roots.1 });
+
+    let first = first_step(estimate(enter), from, to, |n| ahead(n) >= sign * enter);
+    if ahead(first) < sign * enter || ahead(first) > sign * leave {
+        return None;
+    }
+    let left = |n: isize| ahead(n) > sign * leave;
+    let next = first_step(estimate(leave), first, to, left);
+    Some(StepWindow {
+        first,
+        last: Some(if left(next) { next - 1 } else { next }),
+    })
+}
+
+/// Get the window of steps where the probe is within the target along the x
+/// axis, if there is one.
+fn x_window(dx: isize, range: &RangeInclusive<isize>) -> Option<StepWindow> {
+    // Mirror probes launched backwards, so the velocity is never negative.
+    let (min, max) = if dx < 0 {
+        (-*range.end(), -*range.start())
+    } else {
+        (*range.start(), *range.end())
+    };
+    let dx = dx.abs();
+
+    // Drag stops the probe after dx steps, where it stays forever.
+    let stopped = (min..=max).contains(&position(dx, dx));
+    match monotonic_window(dx, 1, dx, (min, max), true) {
+        Some(window) if stopped => Some(StepWindow {
+            last: None,
+            ..window
+        }),
+        None if stopped => Some(StepWindow {
+            first: dx.max(1),
+            last: None,
+        }),
+        window => window,
+    }
+}
+
+/// Get the windows of steps where the probe is within the target along the
+/// y axis, which may be passed through on the way up and on the way down.
+fn y_windows(dy: isize, range: &RangeInclusive<isize>) -> Vec<StepWindow> {
+    let bounds = (*range.start(), *range.end());
+    // The probe rises until step dy, is at the same height on the next step,
+    // then falls forever after that by at least 1 each step. Look no further
+    // than when it must have fallen below the target.
+    let apex = dy.max(0);
+    let below = apex + 2 + (position(dy, apex) - bounds.0).max(0);
+    [
+        monotonic_window(dy, 1, dy, bounds, true),
+        monotonic_window(dy, apex + 1, below, bounds, false),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Error for a target which infinitely many initial velocities can hit: one
+/// which includes the launch height, and where the probe can come to a stop
+/// over it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unbounded;
+
+impl fmt::Display for Unbounded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Infinitely many velocities hit a target which includes the launch height and that the probe can stop over"
+        )
+    }
+}
+
+impl Error for Unbounded {}
+
+/// Get every initial velocity which lands the probe in the target area after
+/// some step, ordered by dx then dy.
+pub fn hitting_velocities(area: &TargetArea) -> Result<Vec<Velocity>, Unbounded> {
+    area.check_bounded()?;
+
+    // Any faster, and the first step would overshoot the target.
+    let dx_limit = area.xrange.start().abs().max(area.xrange.end().abs());
+    let x_windows: Vec<_> = (-dx_limit..=dx_limit)
+        .filter_map(|dx| Some((dx, x_window(dx, &area.xrange)?)))
+        .collect();
+
+    // Similarly for dy, unless the target includes the launch height, as the
+    // probe always passes back through it on step 2dy + 1. Then the limit is
+    // the last step at which the probe is over the target, which there is
+    // as it can't stop over it.
+    let mut dy_limit = area.yrange.start().abs().max(area.yrange.end().abs());
+    if area.yrange.contains(&0) {
+        let last = x_windows.iter().filter_map(|(_, window)| window.last).max();
+        dy_limit = dy_limit.max(last.unwrap_or(0));
+    }
+
+    // Rather than checking every dx against every dy, sort the x windows by
+    // their first step, so those starting by the end of each y window are a
+    // prefix. Windows where the probe stops over the target overlap any y
+    // window they start by, while the rest end within the first dx_limit
+    // steps, so only need checking against y windows starting by then.
+    let (mut stopped, mut moving): (Vec<_>, Vec<_>) = x_windows
+        .into_iter()
+        .partition(|(_, window)| window.last.is_none());
+    stopped.sort_by_key(|(_, window)| window.first);
+    moving.sort_by_key(|(_, window)| window.first);
+    let moving_until = moving
+        .iter()
+        .filter_map(|(_, window)| window.last)
+        .max()
+        .unwrap_or(0);
+
+    let mut velocities = Vec::new();
+    for dy in -dy_limit..=dy_limit {
+        for y_window in y_windows(dy, &area.yrange) {
+            let last = y_window.last.unwrap_or(isize::MAX);
+            let starting = |windows: &[(isize, StepWindow)]| {
+                windows.partition_point(|(_, window)| window.first <= last)
+            };
+            let stopped = &stopped[..starting(&stopped)];
+            velocities.extend(stopped.iter().map(|(dx, _)| (*dx, dy)));
+            if y_window.first <= moving_until {
+                let moving = &moving[..starting(&moving)];
+                velocities.extend(
+                    moving
+                        .iter()
+                        .filter(|(_, window)| window.overlaps(&y_window))
+                        .map(|(dx, _)| (*dx, dy)),
+                );
+            }
+        }
+    }
+
+    // Probes within the target on the way up and down are found twice.
+    velocities.sort_unstable();
+    velocities.dedup();
+    Ok(velocities)
+}
+
+/// How a shot at the target turned out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// The probe was within the target after the given step.
+    Hit { step: usize },
+    /// The probe went past the target, either beyond its far side or through
+    /// it between two steps.
+    Overshot,
+    /// The probe never reached the target, stopping before it or never
+    /// getting high enough.
+    FellShort,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Hit { step } => write!(f, "hit at step {}", step),
+            Self::Overshot => write!(f, "overshot"),
+            Self::FellShort => write!(f, "fell short"),
+        }
+    }
+}
+
+/// Path of the probe for a shot, from the launch position until it hit the
+/// target or could no longer hit it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    /// Position after each step, starting with the launch position.
+    pub positions: Vec<(isize, isize)>,
+    pub outcome: Outcome,
+}
+
+/// Simulate a shot at the target with the given initial velocity.
+pub fn simulate((dx_init, dy_init): Velocity, area: &TargetArea) -> Trajectory {
+    let (mut x, mut y, mut dx, mut dy) = (0, 0, dx_init, dy_init);
+    let mut positions = vec![(x, y)];
+    let mut highest = 0;
+
+    loop {
+        x += dx;
+        y += dy;
+        dx -= dx.signum();
+        dy -= 1;
+        highest = highest.max(y);
+        positions.push((x, y));
+
+        if area.xrange.contains(&x) && area.yrange.contains(&y) {
+            let step = positions.len() - 1;
+            return Trajectory {
+                positions,
+                outcome: Outcome::Hit { step },
+            };
+        }
+
+        // Once the probe is falling below the target, or has stopped or is
+        // moving away from either side of it, it can never come back.
+        let below = dy < 0 && y < *area.yrange.start();
+        let beside = (dx >= 0 && x > *area.xrange.end()) || (dx <= 0 && x < *area.xrange.start());
+        if below || beside {
+            break;
+        }
+    }
+
+    let beyond =
+        (dx_init > 0 && x > *area.xrange.end()) || (dx_init < 0 && x < *area.xrange.start());
+    let outcome = if beyond {
+        Outcome::Overshot
+    } else if !area.xrange.contains(&x) || highest < *area.yrange.start() {
+        Outcome::FellShort
+    } else {
+        Outcome::Overshot
+    };
+    Trajectory { positions, outcome }
+}
+
+/// Draw a trajectory and the target like in the puzzle description, with
+/// the launch position as `S`, the probe's positions as `#` and the target as
+/// `T`.
+pub fn plot(trajectory: &Trajectory, area: &TargetArea) -> String {
+    let corners = [
+        (*area.xrange.start(), *area.yrange.start()),
+        (*area.xrange.end(), *area.yrange.end()),
+    ];
+    let points = || trajectory.positions.iter().chain(corners.iter());
+    let x_min = points().map(|(x, _)| *x).min().unwrap();
+    let x_max = points().map(|(x, _)| *x).max().unwrap();
+    let y_min = points().map(|(_, y)| *y).min().unwrap();
+    let y_max = points().map(|(_, y)| *y).max().unwrap();
+
+    let probe: HashSet<_> = trajectory.positions.iter().skip(1).collect();
+    let mut out = String::new();
+    for y in (y_min..=y_max).rev() {
+        for x in x_min..=x_max {
+            out.push(if (x, y) == (0, 0) {
+                'S'
+            } else if probe.contains(&(x, y)) {
+                '#'
+            } else if area.xrange.contains(&x) && area.yrange.contains(&y) {
+                'T'
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse a velocity of the form e.g. "7,2".
+fn parse_velocity(s: &str) -> Result<Velocity, ParseError> {
+    let components: Vec<isize> = try_parse_input_with(s, |s| s.split(','))?;
+    match components[..] {
+        [dx, dy] => Ok((dx, dy)),
+        _ => Err(ParseError::new(s, "Velocity should be of the form 'DX,DY'")),
+    }
+}
+
+fn solve(input: &TargetArea) -> Vec<Velocity> {
+    hitting_velocities(input).expect("Parsing rejects unbounded targets")
+}
+
+fn part_one(input: &TargetArea) -> i64 {
+    // The highest point is reached when the upwards velocity runs out, or
+    // the start if the probe is never launched upwards.
+    solve(input)
+        .iter()
+        .map(|(_, dy)| position(*dy, (*dy).max(0)))
+        .max()
+        .unwrap_or(0) as i64
+}
+
+fn part_two(input: &TargetArea) -> u64 {
+    solve(input).len() as u64
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = TargetArea;
+    type PartOne = i64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let area: TargetArea = input.parse()?;
+        area.check_bounded()
+            .map_err(|e| ParseError::new(input.trim_end(), e))?;
+        Ok(area)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+
+    /// Plot the shot with a given velocity, or by default the highest shot
+    /// which hits the target.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        let velocity = match args {
+            [] => {
+                let velocities = hitting_velocities(input).map_err(|e| e.to_string())?;
+                *velocities
+                    .iter()
+                    .max_by_key(|(_, dy)| *dy)
+                    .ok_or("No velocity hits the target")?
+            }
+            [flag, value] if flag == "--velocity" => {
+                parse_velocity(value).map_err(|e| format!("Invalid velocity: {}", e))?
+            }
+            _ => return Err("Expected only --velocity DX,DY for day 17".to_string()),
+        };
+
+        let trajectory = simulate(velocity, input);
+        Ok(format!(
+            "{}Velocity {},{}: {}\n",
+            plot(&trajectory, input),
+            velocity.0,
+            velocity.1,
+            trajectory.outcome
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_example() {
+        let area = "target area: x=20..30, y=-10..-5".parse().unwrap();
+
+        // Check each gives the right answer.
+        assert_eq!(part_one(&area), 45);
+        assert_eq!(part_two(&area), 112);
+    }
+
+    /// Find every velocity hitting the target by trying them all.
+    fn brute_force(area: &TargetArea) -> Vec<Velocity> {
+        let mut velocities = Vec::new();
+        for dx_init in -60..=60 {
+            for dy_init in -60..=60 {
+                let (mut x, mut y, mut dx, mut dy): (isize, isize, isize, isize) =
+                    (0, 0, dx_init, dy_init);
+                for _ in 0..200 {
+                    x += dx;
+                    y += dy;
+                    dx -= dx.signum();
+                    dy -= 1;
+                    if area.xrange.contains(&x) && area.yrange.contains(&y) {
+                        velocities.push((dx_init, dy_init));
+                        break;
+                    }
+                }
+            }
+        }
+        velocities
+    }
+
+    #[test]
+    fn test_hitting_velocities() {
+        let area = "target area: x=20..30, y=-10..-5".parse().unwrap();
+        let velocities = hitting_velocities(&area).unwrap();
+        assert!(velocities.contains(&(6, 9)));
+        assert!(velocities.contains(&(7, -1)));
+        assert!(!velocities.contains(&(17, -4)));
+
+        // Targets above, left of, and straddling the launch position.
+        for target in [
+            "target area: x=20..30, y=-10..-5",
+            "target area: x=5..9, y=3..6",
+            "target area: x=-30..-20, y=-10..-5",
+            "target area: x=-3..4, y=-8..-2",
+            "target area: x=11..14, y=-3..2",
+            "target area: x=6..6, y=10..10",
+        ] {
+            let area = target.parse().unwrap();
+            assert_eq!(
+                hitting_velocities(&area),
+                Ok(brute_force(&area)),
+                "{}",
+                target
+            );
+        }
+
+        let area = "target area: x=-2..2, y=-2..2".parse().unwrap();
+        assert_eq!(hitting_velocities(&area), Err(Unbounded));
+
+        // Deep targets take time in proportion to their depth, not its square.
+        let area = "target area: x=20..30, y=-100000..-99995".parse().unwrap();
+        assert_eq!(part_one(&area), 99_999 * 100_000 / 2);
+        assert_eq!(part_two(&area), 238);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let area = "target area: x=20..30, y=-10..-5".parse().unwrap();
+        assert_eq!(simulate((7, 2), &area).outcome, Outcome::Hit { step: 7 });
+        assert_eq!(simulate((6, 3), &area).outcome, Outcome::Hit { step: 9 });
+        assert_eq!(simulate((9, 0), &area).outcome, Outcome::Hit { step: 4 });
+        assert_eq!(simulate((17, -4), &area).outcome, Outcome::Overshot);
+        assert_eq!(simulate((1, 0), &area).outcome, Outcome::FellShort);
+        // Passes straight down through the target between steps.
+        assert_eq!(simulate((6, 30), &area).outcome, Outcome::Overshot);
+
+        let above = "target area: x=5..9, y=30..40".parse().unwrap();
+        assert_eq!(simulate((3, 3), &above).outcome, Outcome::FellShort);
+
+        let trajectory = simulate((7, 2), &area);
+        assert_eq!(trajectory.positions[0], (0, 0));
+        assert_eq!(trajectory.positions[7], (28, -7));
+        assert_eq!(
+            plot(&trajectory, &area),
+            ".............#....#............
+.......#..............#........
+...............................
+S........................#.....
+...............................
+...............................
+...........................#...
+...............................
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+....................TTTTTTTT#TT
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let e = Day17::parse("target area: x=20..30, y=-10..five").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 31, "five"));
+
+        assert!(Day17::parse("target area: x=20..30").is_err());
+
+        let e = Day17::parse("target area: x=30..20, y=-10..-5").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 16, "30..20"));
+        let e = Day17::parse("target area: x=20..30, y=-5..-10").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 26, "-5..-10"));
+
+        // Probes can stop over the target at the launch height forever.
+        let e = Day17::parse("target area: x=2..4, y=-2..2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(e.reason, Unbounded.to_string());
+        assert!(Day17::parse("target area: x=4..5, y=-2..2").is_ok());
+    }
+}
@@ -0,0 +1,98 @@
+/// Registry of the solutions to each day's challenge.
+///
+/// Each day should be added to `DAYS` so that it can be run by the unified
+/// runner.
+use crate::solution::Runner;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+/// The solution to every day which has one.
+pub const DAYS: [&dyn Runner; 17] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+];
+
+/// Get the solution for a day, if there is one.
+pub fn get_day(day: u8) -> Option<&'static dyn Runner> {
+    DAYS.iter().find(|r| r.day() == day).copied()
+}
+
+/// Parse a selection of days: either a single day e.g. "5", an inclusive or
+/// exclusive range e.g. "1..=17" or "1..5", or "all" for every registered day.
+///
+/// Every day selected must have a registered solution.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |s: &str| {
+        s.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid day: {:?}", s))
+    };
+
+    let days: Vec<u8> = if spec == "all" {
+        DAYS.iter().map(|r| r.day()).collect()
+    } else if let Some((start, end)) = spec.split_once("..=") {
+        (parse_day(start)?..=parse_day(end)?).collect()
+    } else if let Some((start, end)) = spec.split_once("..") {
+        (parse_day(start)?..parse_day(end)?).collect()
+    } else {
+        vec![parse_day(spec)?]
+    };
+
+    if days.is_empty() {
+        return Err(format!("No days selected by {:?}", spec));
+    }
+    if let Some(day) = days.iter().find(|d| get_day(**d).is_none()) {
+        return Err(format!("No solution for day {}", day));
+    }
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("1..=3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("1..3"), Ok(vec![1, 2]));
+        assert_eq!(parse_days("all").unwrap().len(), DAYS.len());
+
+        assert!(parse_days("five").is_err());
+        assert!(parse_days("3..3").is_err());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("1..=25").is_err());
+    }
+}
@@ -0,0 +1,257 @@
+/// A two-dimensional grid of values, stored row by row.
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Position of a cell in a grid, as (x, y) from the top left.
+pub type Coord = (usize, usize);
+
+/// Offsets to the adjacent non-diagonal neighbours of a cell.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// Offsets to every adjacent neighbour of a cell, including diagonals.
+const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (0, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+/// Get the positions of the neighbours at the given offsets which are within
+/// a grid of the given size.
+fn neighbours_within(
+    (x, y): Coord,
+    width: usize,
+    height: usize,
+    offsets: &[(isize, isize)],
+) -> impl Iterator<Item = Coord> + '_ {
+    offsets.iter().filter_map(move |(dx, dy)| {
+        let nx = x.checked_add_signed(*dx).filter(|nx| *nx < width)?;
+        let ny = y.checked_add_signed(*dy).filter(|ny| *ny < height)?;
+        Some((nx, ny))
+    })
+}
+
+/// Get the positions of all adjacent non-diagonal neighbours within a grid of
+/// the given size, for views of a grid which don't store every cell.
+pub fn neighbours4_within(
+    coord: Coord,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = Coord> {
+    neighbours_within(coord, width, height, &ORTHOGONAL)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells given row by row.
+    ///
+    /// Panics if the number of cells doesn't fill the grid.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Cells don't fill the grid");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Create a grid by calling a function with the position of every cell.
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Coord) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(f)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn index_of(&self, (x, y): Coord) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(move |i| &mut self.cells[i])
+    }
+
+    /// Get the positions of all adjacent non-diagonal neighbours.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        neighbours_within(coord, self.width, self.height, &ORTHOGONAL)
+    }
+
+    /// Get the positions of all adjacent neighbours, including diagonals.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        neighbours_within(coord, self.width, self.height, &ADJACENT)
+    }
+
+    /// Get the position of every cell, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Get every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// Get every cell mutably, row by row.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+/// Parse a grid of single digits, one row per line.
+impl<T: From<u8>> FromStr for Grid<T> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        let mut offset = 0;
+        for line in s.split_inclusive('\n') {
+            let line_offset = offset;
+            offset += line.len();
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            for (i, ch) in line.char_indices() {
+                let digit = ch.to_digit(10).ok_or_else(|| {
+                    ParseError::new(&line[i..i + ch.len_utf8()], "Expected a digit")
+                        .offset_by(s, line_offset + i)
+                })?;
+                cells.push(T::from(digit as u8));
+            }
+
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return Err(ParseError::new(line, "Rows should all be the same length")
+                    .offset_by(s, line_offset));
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid: Grid<u8> = "123\n456".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let e = "123\n4x6".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+        let e = "123\n45".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, vec![0; 9]);
+
+        let mut corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        corner.sort_unstable();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+
+        let mut corner: Vec<_> = grid.neighbours8((2, 2)).collect();
+        corner.sort_unstable();
+        assert_eq!(corner, vec![(1, 1), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(grid.row(1), &[10, 11, 12]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 12]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &11)));
+    }
+}
@@ -0,0 +1,150 @@
+/// Locating and reading the puzzle input for each day.
+use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Environment variable giving the directory to read input files from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// Read from standard input.
+    Stdin,
+    /// Read from a single file, whatever the day.
+    File(PathBuf),
+    /// Read each day's file, e.g. "05.txt" for day 5, from a directory.
+    Dir(PathBuf),
+}
+
+impl InputSource {
+    /// Resolve where to read input from.
+    ///
+    /// A path given on the command line takes priority, where "-" means
+    /// standard input and a directory holds a file for each day. Otherwise the
+    /// directory in the `AOC_INPUT_DIR` environment variable is used, falling
+    /// back to the "input" folder in the crate's manifest directory.
+    pub fn resolve(arg: Option<&str>) -> Self {
+        Self::resolve_from(arg, env::var_os(INPUT_DIR_VAR))
+    }
+
+    fn resolve_from(arg: Option<&str>, env_dir: Option<OsString>) -> Self {
+        match (arg, env_dir) {
+            (Some("-"), _) => Self::Stdin,
+            (Some(path), _) => {
+                let path = PathBuf::from(path);
+                if path.is_dir() {
+                    Self::Dir(path)
+                } else {
+                    Self::File(path)
+                }
+            }
+            (None, Some(dir)) if !dir.is_empty() => Self::Dir(PathBuf::from(dir)),
+            _ => Self::Dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input")),
+        }
+    }
+
+    /// Whether the same input is read whatever the day.
+    pub fn is_single(&self) -> bool {
+        !matches!(self, Self::Dir(_))
+    }
+
+    /// Read the puzzle input for a day.
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            Self::File(path) => read_file(path.clone()),
+            Self::Dir(dir) => read_file(dir.join(format!("{:02}.txt", day))),
+        }
+    }
+}
+
+fn read_file(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|source| InputError::File { path, source })
+}
+
+/// Error from reading the puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file couldn't be read.
+    File { path: PathBuf, source: io::Error },
+    /// Standard input couldn't be read.
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::File { path, source } => {
+                write!(
+                    f,
+                    "Could not read input file {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            Self::Stdin(source) => write!(f, "Could not read input from stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::File { source, .. } => Some(source),
+            Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let manifest_input = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input");
+
+        assert_eq!(
+            InputSource::resolve_from(Some("-"), None),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve_from(Some("input/05.txt"), Some("elsewhere".into())),
+            InputSource::File(PathBuf::from("input/05.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_from(Some(manifest_input.to_str().unwrap()), None),
+            InputSource::Dir(manifest_input.clone())
+        );
+        assert_eq!(
+            InputSource::resolve_from(None, Some("elsewhere".into())),
+            InputSource::Dir(PathBuf::from("elsewhere"))
+        );
+        assert_eq!(
+            InputSource::resolve_from(None, None),
+            InputSource::Dir(manifest_input)
+        );
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = InputSource::Dir(PathBuf::from("does/not/exist"));
+        match source.read(1) {
+            Err(InputError::File { path, .. }) => {
+                assert_eq!(path, PathBuf::from("does/not/exist/01.txt"))
+            }
+            other => panic!("Expected a file error, got {:?}", other),
+        }
+    }
+}
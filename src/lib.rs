@@ -2,11 +2,25 @@
 ///
 /// Public API should be accessible within compiled binaries.
 ///
-use std::fmt::Debug;
-use std::fs;
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
 use std::str::FromStr;
 use std::time::Instant;
 
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod pathfinding;
+pub mod solution;
+
+pub use input::{InputError, InputSource};
+pub use solution::Solution;
+
 /// Time a closure in microseconds and print the results.
 pub fn print_elapsed_time<T, F>(function: F) -> T
 where
@@ -18,34 +32,188 @@ where
     ret
 }
 
-/// Get a string read from a file in the "input" folder.
-pub fn get_day_input(day: &'static str) -> String {
-    let input_file = format!("input/{}.txt", day);
-    fs::read_to_string(&input_file)
-        .unwrap_or_else(|_| panic!("Could not read input file {}", &input_file))
+/// Get the input for a day from its default location: see
+/// `InputSource::resolve`.
+pub fn get_day_input(day: u8) -> Result<String, InputError> {
+    InputSource::resolve(None).read(day)
+}
+
+/// Error from parsing an input string, locating the text which couldn't be
+/// parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Line of the input the text is on, starting from 1.
+    pub line: usize,
+    /// Column of the line the text starts at, starting from 1.
+    pub column: usize,
+    /// The text which couldn't be parsed.
+    pub text: String,
+    /// Why the text couldn't be parsed.
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Create an error for some text which couldn't be parsed, located at the
+    /// start of the string being parsed.
+    pub fn new(text: &str, reason: impl ToString) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Relocate an error made relative to a part of `input` which starts
+    /// `offset` bytes into it, so that it is relative to `input` instead.
+    pub fn offset_by(mut self, input: &str, offset: usize) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        if self.line == 1 {
+            self.column += before[line_start..].chars().count();
+        }
+        self.line += before.matches('\n').count();
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {:?}: {}",
+            self.line, self.column, self.text, self.reason
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> Self {
+        Self::new("", e)
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+/// Get the byte offset of a part of a string within the whole string, or 0 if
+/// it isn't part of the string.
+pub(crate) fn offset_within(whole: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(whole.as_ptr() as usize);
+    if offset <= whole.len() {
+        offset
+    } else {
+        0
+    }
+}
+
+/// Try to get a collection of parseables from an input string, separating it
+/// by a generic function.
+///
+/// The first part which fails to parse gives an error, located within the
+/// input string.
+pub fn try_parse_input_with<'a, F, S, C, T>(input: &'a str, with: F) -> Result<C, ParseError>
+where
+    F: Fn(&'a str) -> S,
+    S: Iterator<Item = &'a str>,
+    C: FromIterator<T>,
+    T: FromStr,
+    <T as FromStr>::Err: Into<ParseError>,
+{
+    with(input)
+        .map(|s| {
+            s.parse().map_err(|e: T::Err| {
+                let mut e: ParseError = e.into();
+                if e.text.is_empty() {
+                    e.text = s.to_string();
+                }
+                e.offset_by(input, offset_within(input, s))
+            })
+        })
+        .collect()
+}
+
+/// Try to get a collection of parseables from an input string splitting on
+/// lines.
+pub fn try_parse_input_lines<C, T>(input: &str) -> Result<C, ParseError>
+where
+    C: FromIterator<T>,
+    T: FromStr,
+    <T as FromStr>::Err: Into<ParseError>,
+{
+    try_parse_input_with(input, str::lines)
 }
 
 /// Get a collection of parseables from an input string, separating it by a
 /// generic function.
+///
+/// Panics if any part fails to parse.
 pub fn parse_input_with<'a, F, S, C, T>(input: &'a str, with: F) -> C
 where
     F: Fn(&'a str) -> S,
     S: Iterator<Item = &'a str>,
     C: FromIterator<T>,
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Into<ParseError>,
 {
-    with(input)
-        .map(|s| s.parse().expect("Failed to parse input"))
-        .collect()
+    try_parse_input_with(input, with).unwrap_or_else(|e| panic!("Failed to parse input: {}", e))
 }
 
 /// Get a collection of parseables from an input string splitting on lines.
+///
+/// Panics if any line fails to parse.
 pub fn parse_input_lines<C, T>(input: &str) -> C
 where
     C: FromIterator<T>,
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Into<ParseError>,
 {
     parse_input_with(input, str::lines)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_parse_input_lines() {
+        let parsed: Result<Vec<u32>, _> = try_parse_input_lines("1\n2\n3");
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let parsed: Result<Vec<u32>, _> = try_parse_input_lines("1\n2\nthree");
+        let e = parsed.unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "three"));
+    }
+
+    #[test]
+    fn test_try_parse_input_with() {
+        let parsed: Result<Vec<u32>, _> = try_parse_input_with("1,2,x", |s| s.split(','));
+        let e = parsed.unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 5, "x"));
+    }
+
+    #[test]
+    fn test_nested_parse_error() {
+        #[derive(Debug)]
+        struct Row(Vec<u32>);
+
+        impl FromStr for Row {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(try_parse_input_with(s, |s| s.split(' '))?))
+            }
+        }
+
+        let parsed: Vec<Row> = try_parse_input_lines("1 2\n3 4").unwrap();
+        assert_eq!(parsed[1].0, vec![3, 4]);
+
+        let parsed: Result<Vec<Row>, _> = try_parse_input_lines("1 2\n3 4 five 6");
+        let e = parsed.unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "five"));
+    }
+}
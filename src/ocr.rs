@@ -0,0 +1,250 @@
+/// Reading the capital letters some puzzles draw in lit cells of a grid.
+use crate::grid::Grid;
+use std::error::Error;
+use std::fmt;
+
+/// Letters of the small font, each drawn 4 cells wide and 6 tall, with the
+/// gap after each letter included in its rows.
+///
+/// Y is the exception which is 5 cells wide, filling the gap after it.
+const SMALL_GLYPHS: &[(char, &[&str])] = &[
+    ('A', &[".##..", "#..#.", "#..#.", "####.", "#..#.", "#..#."]),
+    ('B', &["###..", "#..#.", "###..", "#..#.", "#..#.", "###.."]),
+    ('C', &[".##..", "#..#.", "#....", "#....", "#..#.", ".##.."]),
+    ('E', &["####.", "#....", "###..", "#....", "#....", "####."]),
+    ('F', &["####.", "#....", "###..", "#....", "#....", "#...."]),
+    ('G', &[".##..", "#..#.", "#....", "#.##.", "#..#.", ".###."]),
+    ('H', &["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]),
+    ('I', &[".###.", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('J', &["..##.", "...#.", "...#.", "...#.", "#..#.", ".##.."]),
+    ('K', &["#..#.", "#.#..", "##...", "#.#..", "#.#..", "#..#."]),
+    ('L', &["#....", "#....", "#....", "#....", "#....", "####."]),
+    ('O', &[".##..", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('P', &["###..", "#..#.", "#..#.", "###..", "#....", "#...."]),
+    ('R', &["###..", "#..#.", "#..#.", "###..", "#.#..", "#..#."]),
+    ('S', &[".###.", "#....", "#....", ".##..", "...#.", "###.."]),
+    ('U', &["#..#.", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####.", "...#.", "..#..", ".#...", "#....", "####."]),
+];
+
+/// Letters of the large font, each drawn 6 cells wide and 10 tall, with the
+/// gap after each letter included in its rows.
+#[rustfmt::skip]
+const LARGE_GLYPHS: &[(char, &[&str])] = &[
+    ('A', &["..##....", ".#..#...", "#....#..", "#....#..", "#....#..", "######..", "#....#..", "#....#..", "#....#..", "#....#.."]),
+    ('B', &["#####...", "#....#..", "#....#..", "#....#..", "#####...", "#....#..", "#....#..", "#....#..", "#....#..", "#####..."]),
+    ('C', &[".####...", "#....#..", "#.......", "#.......", "#.......", "#.......", "#.......", "#.......", "#....#..", ".####..."]),
+    ('E', &["######..", "#.......", "#.......", "#.......", "#####...", "#.......", "#.......", "#.......", "#.......", "######.."]),
+    ('F', &["######..", "#.......", "#.......", "#.......", "#####...", "#.......", "#.......", "#.......", "#.......", "#......."]),
+    ('G', &[".####...", "#....#..", "#.......", "#.......", "#.......", "#..###..", "#....#..", "#....#..", "#...##..", ".###.#.."]),
+    ('H', &["#....#..", "#....#..", "#....#..", "#....#..", "######..", "#....#..", "#....#..", "#....#..", "#....#..", "#....#.."]),
+    ('J', &["...###..", "....#...", "....#...", "....#...", "....#...", "....#...", "....#...", "#...#...", "#...#...", ".###...."]),
+    ('K', &["#....#..", "#...#...", "#..#....", "#.#.....", "##......", "##......", "#.#.....", "#..#....", "#...#...", "#....#.."]),
+    ('L', &["#.......", "#.......", "#.......", "#.......", "#.......", "#.......", "#.......", "#.......", "#.......", "######.."]),
+    ('N', &["#....#..", "##...#..", "##...#..", "#.#..#..", "#.#..#..", "#..#.#..", "#..#.#..", "#...##..", "#...##..", "#....#.."]),
+    ('P', &["#####...", "#....#..", "#....#..", "#....#..", "#####...", "#.......", "#.......", "#.......", "#.......", "#......."]),
+    ('R', &["#####...", "#....#..", "#....#..", "#....#..", "#####...", "#..#....", "#...#...", "#...#...", "#....#..", "#....#.."]),
+    ('X', &["#....#..", "#....#..", ".#..#...", ".#..#...", "..##....", "..##....", ".#..#...", ".#..#...", "#....#..", "#....#.."]),
+    ('Z', &["######..", ".....#..", ".....#..", "....#...", "...#....", "..#.....", ".#......", "#.......", "#.......", "######.."]),
+];
+
+/// A font of capital letters drawn side by side with a gap between each.
+#[derive(Debug, Clone, Copy)]
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    /// Number of columns between each letter.
+    pub gap: usize,
+    /// Each letter it can draw, along with the rows drawing it and the gap
+    /// after it.
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The font letters are usually drawn in, 4 cells wide and 6 tall.
+pub const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    gap: 1,
+    glyphs: SMALL_GLYPHS,
+};
+
+/// The font letters are sometimes drawn in, 6 cells wide and 10 tall.
+pub const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    gap: 2,
+    glyphs: LARGE_GLYPHS,
+};
+
+/// Error from reading letters which aren't in a font.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OcrError {
+    /// No font is as tall as the grid.
+    UnknownHeight(usize),
+    /// The cells of a letter which isn't in the font.
+    UnknownGlyph {
+        /// Index of the letter, starting from 0.
+        index: usize,
+        /// Column of the grid the letter starts at.
+        column: usize,
+        /// The letter drawn with '#' for lit cells and '.' for others.
+        glyph: String,
+    },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownHeight(height) => {
+                write!(f, "No font is {} cells tall", height)
+            }
+            Self::UnknownGlyph {
+                index,
+                column,
+                glyph,
+            } => write!(
+                f,
+                "Letter {} at column {} isn't in the font:\n{}",
+                index, column, glyph
+            ),
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+impl Font {
+    /// Read the letters drawn in a grid, where the first starts at the left
+    /// hand edge and any letter with no cells lit is a space.
+    ///
+    /// The gap after each letter has to be empty, other than for letters
+    /// which are drawn across it, so a grid which doesn't line up with the
+    /// letters gives an unknown glyph rather than misreading. Cells past the
+    /// edges of the grid are treated as not lit, so the gap after the last
+    /// letter is optional unless the letter is drawn across it.
+    pub fn read(&self, grid: &Grid<bool>) -> Result<String, OcrError> {
+        if grid.height() != self.height {
+            return Err(OcrError::UnknownHeight(grid.height()));
+        }
+
+        let stride = self.width + self.gap;
+        (0..grid.width().div_ceil(stride))
+            .map(|index| {
+                let column = index * stride;
+                let rows: Vec<String> = (0..self.height)
+                    .map(|y| {
+                        (column..column + stride)
+                            .map(|x| match grid.get((x, y)) {
+                                Some(true) => '#',
+                                _ => '.',
+                            })
+                            .collect()
+                    })
+                    .collect();
+
+                if rows.iter().all(|row| !row.contains('#')) {
+                    return Ok(' ');
+                }
+                self.glyphs
+                    .iter()
+                    .find(|(_, glyph)| *glyph == rows)
+                    .map(|(letter, _)| *letter)
+                    .ok_or_else(|| OcrError::UnknownGlyph {
+                        index,
+                        column,
+                        glyph: rows.join("\n"),
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Read the letters drawn in a grid, in whichever font is as tall as it.
+pub fn read(grid: &Grid<bool>) -> Result<String, OcrError> {
+    [SMALL, LARGE]
+        .iter()
+        .find(|font| font.height == grid.height())
+        .ok_or(OcrError::UnknownHeight(grid.height()))?
+        .read(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draw text in a font with every letter and gap.
+    fn draw(font: &Font, text: &str) -> Grid<bool> {
+        let letters: Vec<&[&str]> = text
+            .chars()
+            .map(|ch| {
+                font.glyphs
+                    .iter()
+                    .find(|(letter, _)| *letter == ch)
+                    .unwrap()
+                    .1
+            })
+            .collect();
+        let stride = font.width + font.gap;
+        Grid::from_fn(letters.len() * stride, font.height, |(x, y)| {
+            letters[x / stride][y].as_bytes()[x % stride] == b'#'
+        })
+    }
+
+    #[test]
+    fn test_read() {
+        let small: String = SMALL.glyphs.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(read(&draw(&SMALL, &small)), Ok(small));
+        let large: String = LARGE.glyphs.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(read(&draw(&LARGE, &large)), Ok(large));
+
+        // Without the last gap, and with the second letter left blank.
+        let grid = draw(&SMALL, "HOLA");
+        let grid = Grid::from_fn(grid.width() - 1, 6, |(x, y)| {
+            !(5..10).contains(&x) && grid[(x, y)]
+        });
+        assert_eq!(read(&grid), Ok("H LA".to_string()));
+    }
+
+    #[test]
+    fn test_read_puzzle_output() {
+        // Day 13's folded paper.
+        let drawing = "\
+#..#.####...##.#..#...##.###...##....##.
+#..#.#.......#.#..#....#.#..#.#..#....#.
+####.###.....#.####....#.#..#.#.......#.
+#..#.#.......#.#..#....#.###..#.......#.
+#..#.#....#..#.#..#.#..#.#.#..#..#.#..#.
+#..#.####..##..#..#..##..#..#..##...##..";
+        let rows: Vec<&str> = drawing.lines().collect();
+        let grid = Grid::from_fn(rows[0].len(), rows.len(), |(x, y)| {
+            rows[y].as_bytes()[x] == b'#'
+        });
+        assert_eq!(read(&grid), Ok("HEJHJRCJ".to_string()));
+
+        // Shifted by a column, so the gaps don't line up with the letters.
+        let shifted = Grid::from_fn(grid.width() + 1, 6, |(x, y)| x > 0 && grid[(x - 1, y)]);
+        assert!(matches!(
+            read(&shifted),
+            Err(OcrError::UnknownGlyph { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_glyphs() {
+        let mut grid = draw(&SMALL, "ABC");
+        grid[(8, 0)] = true;
+        assert_eq!(
+            read(&grid),
+            Err(OcrError::UnknownGlyph {
+                index: 1,
+                column: 5,
+                glyph: "####.\n#..#.\n###..\n#..#.\n#..#.\n###..".to_string(),
+            })
+        );
+        assert_eq!(
+            read(&Grid::new(1, 7, vec![false; 7])),
+            Err(OcrError::UnknownHeight(7))
+        );
+    }
+}
@@ -0,0 +1,235 @@
+/// Shortest path searches over any graph, described by a function giving the
+/// neighbours of each node.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path found through a graph, from the start node to the goal node
+/// inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Total cost of every step along the path.
+    pub cost: C,
+    /// Every node along the path, in order.
+    pub nodes: Vec<N>,
+}
+
+/// Nodes discovered so far by a search, each given an index in the order they
+/// were discovered, along with the index of the node before them on the best
+/// path found to them.
+struct Discovered<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Eq + Hash + Clone> Discovered<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            parents: vec![None],
+        }
+    }
+
+    /// Add a newly discovered node, giving its index.
+    fn insert(&mut self, node: N, parent: usize) -> usize {
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.parents.push(Some(parent));
+        index
+    }
+
+    /// Follow the parents of a node back to the start to get its path.
+    fn path_to(&self, index: usize) -> Vec<N> {
+        let mut path = Vec::new();
+        let mut current = Some(index);
+        while let Some(i) = current {
+            path.push(self.nodes[i].clone());
+            current = self.parents[i];
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Find the cheapest path from the start node to a goal node with the A*
+/// algorithm.
+///
+/// The heuristic estimates the cost from a node to the nearest goal, and must
+/// never overestimate it for the path found to be the cheapest. The cost type's
+/// default value is taken as zero cost.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut neighbours: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut discovered = Discovered::new(start.clone());
+    let mut best_cost = vec![C::default()];
+
+    // Maintain open as a min heap ordered by estimated total cost: wrapping
+    // each value in Reverse turns BinaryHeap from a max to a min heap.
+    let mut open = BinaryHeap::new();
+    open.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, index))) = open.pop() {
+        // Skip entries made stale by finding a cheaper path to the node.
+        if cost > best_cost[index] {
+            continue;
+        }
+
+        let current = discovered.nodes[index].clone();
+        if is_goal(&current) {
+            return Some(Path {
+                cost,
+                nodes: discovered.path_to(index),
+            });
+        }
+
+        for (neighbour, step) in neighbours(&current) {
+            let new_cost = cost + step;
+            let neighbour_index = match discovered.indices.get(&neighbour) {
+                Some(&i) if new_cost >= best_cost[i] => continue,
+                Some(&i) => {
+                    discovered.parents[i] = Some(index);
+                    best_cost[i] = new_cost;
+                    i
+                }
+                None => {
+                    best_cost.push(new_cost);
+                    discovered.insert(neighbour.clone(), index)
+                }
+            };
+            open.push(Reverse((
+                new_cost + heuristic(&neighbour),
+                new_cost,
+                neighbour_index,
+            )));
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path from the start node to a goal node with Dijkstra's
+/// algorithm.
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, neighbours: FN, is_goal: FG) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Find the path with the fewest steps from the start node to a goal node
+/// with a breadth-first search, where the cost is the number of steps.
+pub fn bfs<N, FN, IN, FG>(start: N, mut neighbours: FN, mut is_goal: FG) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut discovered = Discovered::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let current = discovered.nodes[index].clone();
+        if is_goal(&current) {
+            let nodes = discovered.path_to(index);
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+
+        for neighbour in neighbours(&current) {
+            if !discovered.indices.contains_key(&neighbour) {
+                queue.push_back(discovered.insert(neighbour, index));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small graph where the direct route isn't the cheapest.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('e', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3), ('e', 5)],
+            'd' => vec![('e', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', edges, |n| *n == 'e').unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.nodes, vec!['a', 'b', 'c', 'd', 'e']);
+
+        assert_eq!(dijkstra('e', edges, |n| *n == 'a'), None);
+        assert_eq!(
+            dijkstra('a', edges, |n| *n == 'a'),
+            Some(Path {
+                cost: 0,
+                nodes: vec!['a']
+            })
+        );
+    }
+
+    #[test]
+    fn test_astar_and_bfs() {
+        // An open 10x10 grid where moving down costs 2 and right costs 1.
+        let moves = |&(x, y): &(u32, u32)| {
+            let mut moves = Vec::new();
+            if x < 9 {
+                moves.push(((x + 1, y), 1));
+            }
+            if y < 9 {
+                moves.push(((x, y + 1), 2));
+            }
+            moves
+        };
+        let manhattan = |&(x, y): &(u32, u32)| (9 - x) + (9 - y);
+
+        let path = astar((0, 0), moves, manhattan, |n| *n == (9, 9)).unwrap();
+        assert_eq!(path.cost, 27);
+        assert_eq!(path.nodes.len(), 19);
+        assert_eq!(path.nodes.last(), Some(&(9, 9)));
+
+        let path = bfs(
+            (0, 0),
+            |n| moves(n).into_iter().map(|(n, _)| n),
+            |n| *n == (9, 9),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes[0], (0, 0));
+
+        assert_eq!(
+            bfs('e', |n| edges(n).into_iter().map(|(n, _)| n), |n| *n == 'a'),
+            None
+        );
+    }
+}
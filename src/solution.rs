@@ -0,0 +1,111 @@
+/// Common interface for the solution to each day's challenge.
+use crate::bench::{self, BenchConfig, BenchReport};
+use crate::{print_elapsed_time, ParseError};
+use std::fmt::Display;
+
+/// A solution to a day's challenge, split into parsing the puzzle input and
+/// then solving each part from the parsed input.
+pub trait Solution {
+    /// The day of the challenge this solves.
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared between both parts.
+    type Input;
+    /// The answer to part one.
+    type PartOne: Display;
+    /// The answer to part two.
+    type PartTwo: Display;
+
+    /// Parse the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve part one from the parsed puzzle input.
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    /// Solve part two from the parsed puzzle input.
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// Inspect the parsed puzzle input in a way specific to this day, such as
+    /// drawing it, given any arguments left over on the command line. Gives
+    /// the output to print.
+    ///
+    /// By default a day has nothing to inspect.
+    fn inspect(input: &Self::Input, args: &[String]) -> Result<String, String> {
+        let _ = (input, args);
+        Err(format!("Day {:02} has nothing to inspect", Self::DAY))
+    }
+}
+
+/// Object-safe view of a `Solution`, so that any day can be driven without
+/// knowing the types of its input or answers.
+///
+/// Implemented for every `Solution`.
+pub trait Runner {
+    /// The day of the challenge this solves.
+    fn day(&self) -> u8;
+
+    /// Parse the puzzle input and solve both parts, giving the answers as
+    /// strings.
+    fn solve(&self, input: &str) -> Result<(String, String), String>;
+
+    /// Parse the puzzle input and solve both parts, printing the answers and
+    /// how long each part took.
+    fn run(&self, input: &str) -> Result<(), String>;
+
+    /// Benchmark parsing the puzzle input and solving each part.
+    fn bench(&self, input: &str, config: &BenchConfig) -> Result<BenchReport, String>;
+
+    /// Parse the puzzle input and inspect it in a way specific to the day.
+    fn inspect(&self, input: &str, args: &[String]) -> Result<String, String>;
+}
+
+/// Format an answer to go after its label, starting multi-line answers on
+/// their own line.
+fn format_answer(answer: String) -> String {
+    if answer.contains('\n') {
+        format!("\n{}", answer)
+    } else {
+        format!(" {}", answer)
+    }
+}
+
+fn parse_error<S: Solution>(e: &ParseError) -> String {
+    format!("Failed to parse input for day {:02}: {}", S::DAY, e)
+}
+
+fn parse<S: Solution>(input: &str) -> Result<S::Input, String> {
+    S::parse(input).map_err(|e| parse_error::<S>(&e))
+}
+
+impl<S: Solution> Runner for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str) -> Result<(String, String), String> {
+        let parsed = parse::<S>(input)?;
+        Ok((
+            S::part_one(&parsed).to_string(),
+            S::part_two(&parsed).to_string(),
+        ))
+    }
+
+    fn run(&self, input: &str) -> Result<(), String> {
+        let parsed = parse::<S>(input)?;
+        println!("Day {:02}:", S::DAY);
+        println!("==========");
+        let part_one = print_elapsed_time(|| S::part_one(&parsed));
+        println!("Part one:{}", format_answer(part_one.to_string()));
+        let part_two = print_elapsed_time(|| S::part_two(&parsed));
+        println!("Part two:{}", format_answer(part_two.to_string()));
+        Ok(())
+    }
+
+    fn bench(&self, input: &str, config: &BenchConfig) -> Result<BenchReport, String> {
+        bench::bench::<S>(input, config).map_err(|e| parse_error::<S>(&e))
+    }
+
+    fn inspect(&self, input: &str, args: &[String]) -> Result<String, String> {
+        S::inspect(&parse::<S>(input)?, args)
+    }
+}